@@ -0,0 +1,210 @@
+// src/aoc/examples.rs
+use super::puzzle::{Block, Puzzle};
+use crate::input;
+use crate::state::DayState;
+use anyhow::{bail, Context, Result};
+use std::fs;
+use std::path::Path;
+
+/// An example input found in a puzzle description.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    pub part: u8,
+    pub input: String,
+    /// The highlighted answer for the example, when the description has one
+    pub answer: Option<String>,
+}
+
+impl Example {
+    /// The answer as a number, which is what the day's `partN` functions return.
+    pub fn numeric_answer(&self) -> Option<usize> {
+        self.answer.as_deref()?.parse().ok()
+    }
+}
+
+fn mentions_example(block: &Block) -> bool {
+    matches!(block, Block::Paragraph(_)) && block.text().to_lowercase().contains("example")
+}
+
+/// The last emphasized code span of a part, which is how the site highlights example answers.
+fn highlighted_answer(blocks: &[Block]) -> Option<String> {
+    blocks
+        .iter()
+        .filter_map(|block| match block {
+            Block::Paragraph(spans) | Block::ListItem(spans) => Some(spans),
+            _ => None,
+        })
+        .flatten()
+        .filter(|span| span.style.code && span.style.em)
+        .map(|span| span.text.trim().to_string())
+        .next_back()
+}
+
+/// Find the example input, and its answer, of each part of a puzzle.
+///
+/// The example is the first code block following a paragraph that mentions "example",
+/// falling back to the first multi-line code block. Part two reuses the example of
+/// part one when it has none of its own.
+pub fn extract(puzzle: &Puzzle) -> Vec<Example> {
+    let mut examples: Vec<Example> = Vec::new();
+    for (i, blocks) in puzzle.parts.iter().enumerate() {
+        let part = i as u8 + 1;
+        let is_candidate =
+            |block: &Block| matches!(block, Block::Code(_)) && block.text().contains('\n');
+        let input = blocks
+            .windows(2)
+            .find(|pair| mentions_example(&pair[0]) && is_candidate(&pair[1]))
+            .map(|pair| &pair[1])
+            .or_else(|| blocks.iter().find(|block| is_candidate(block)))
+            .map(|block| block.text())
+            .or_else(|| examples.last().map(|example| example.input.clone()));
+
+        if let Some(input) = input {
+            examples.push(Example {
+                part,
+                input,
+                answer: highlighted_answer(blocks),
+            });
+        }
+    }
+    examples
+}
+
+/// Store the examples as inputs of the day, remembering their expected answers.
+pub fn save(root: &Path, day: u8, examples: &[Example]) -> Result<()> {
+    let mut state = DayState::load(root, day)?;
+    for example in examples {
+        let path = root.join(input::example_path(day, example.part));
+        fs::create_dir_all(path.parent().unwrap())?;
+        fs::write(&path, &example.input).with_context(|| format!("Failed to write {:?}", path))?;
+        state.part_mut(example.part).example_answer = example.answer.clone();
+    }
+    state.save(root, day)
+}
+
+/// The `#[test]` functions checking `examples`, skipping ones `source` already has.
+fn render_tests(source: &str, examples: &[Example]) -> String {
+    let mut out = String::new();
+    let mut previous: Option<&Example> = None;
+    for example in examples {
+        let Some(answer) = example.numeric_answer() else {
+            continue;
+        };
+        let test = format!("fn test_part{}_example()", example.part);
+        if source.contains(&test) {
+            continue;
+        }
+        let constant = match previous {
+            Some(p) if p.input == example.input => format!("EXAMPLE_PART{}", p.part),
+            _ => {
+                let constant = format!("EXAMPLE_PART{}", example.part);
+                // A line continuation keeps the example flush left, but would also eat its
+                // leading whitespace
+                let continuation = if example.input.starts_with(char::is_whitespace) {
+                    ""
+                } else {
+                    "\\\n"
+                };
+                out.push_str(&format!(
+                    "\n    const {}: &str = \"{}{}\";\n",
+                    constant,
+                    continuation,
+                    example.input.replace('\\', "\\\\").replace('"', "\\\"")
+                ));
+                previous = Some(example);
+                constant
+            }
+        };
+        out.push_str(&format!(
+            "\n    #[test]\n    {} {{\n        assert_eq!(part{}({}), {});\n    }}\n",
+            test, example.part, constant, answer
+        ));
+    }
+    out
+}
+
+/// Append tests for `examples` to the `tests` module at the end of a day's `lib.rs`.
+///
+/// Returns how many tests were added.
+pub fn add_tests(lib: &Path, examples: &[Example]) -> Result<usize> {
+    let source = fs::read_to_string(lib).with_context(|| format!("Failed to read {:?}", lib))?;
+    let tests = render_tests(&source, examples);
+    if tests.is_empty() {
+        return Ok(0);
+    }
+    if !source.contains("mod tests {") {
+        bail!("{:?} has no `mod tests` to add the examples to", lib);
+    }
+    let Some(end) = source.trim_end().strip_suffix('}') else {
+        bail!("{:?} does not end with the `tests` module", lib);
+    };
+
+    let updated = format!("{}{}}}\n", end, tests);
+    fs::write(lib, updated).with_context(|| format!("Failed to write {:?}", lib))?;
+    Ok(tests.matches("#[test]").count())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc::puzzle::{parse, tests::PAGE};
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_extract_examples() {
+        let examples = extract(&parse(PAGE));
+        assert_eq!(
+            examples,
+            vec![
+                Example {
+                    part: 1,
+                    input: "3   4\n4   3\n2   5\n".into(),
+                    answer: Some("11".into()),
+                },
+                Example {
+                    part: 2,
+                    input: "1 < 2\n".into(),
+                    answer: Some("31".into()),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_add_tests() -> Result<()> {
+        let dir = TempDir::new()?;
+        let lib = dir.path().join("lib.rs");
+        fs::write(
+            &lib,
+            "pub fn part1(input: &str) -> usize {\n    input.len()\n}\n\n#[cfg(test)]\nmod tests {\n    use super::*;\n}\n",
+        )?;
+        let examples = [
+            Example {
+                part: 1,
+                input: "a \"b\"\n".into(),
+                answer: Some("7".into()),
+            },
+            Example {
+                part: 2,
+                input: "a \"b\"\n".into(),
+                answer: None,
+            },
+        ];
+
+        assert_eq!(add_tests(&lib, &examples)?, 1);
+        let source = fs::read_to_string(&lib)?;
+        assert!(source.contains("const EXAMPLE_PART1: &str = \"\\\na \\\"b\\\"\n\";"));
+        assert!(source.contains("assert_eq!(part1(EXAMPLE_PART1), 7);"));
+        assert!(source.ends_with("    }\n}\n"));
+
+        // Running it again does not duplicate the tests
+        assert_eq!(add_tests(&lib, &examples)?, 0);
+
+        save(dir.path(), 3, &examples)?;
+        let saved = fs::read_to_string(dir.path().join(input::example_path(3, 2)))?;
+        assert_eq!(saved, "a \"b\"\n");
+        let state = DayState::load(dir.path(), 3)?;
+        assert_eq!(state.part(1).example_answer.as_deref(), Some("7"));
+        Ok(())
+    }
+}
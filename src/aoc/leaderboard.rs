@@ -0,0 +1,269 @@
+// src/aoc/leaderboard.rs
+use super::{format_elapsed, unlock_time, Client, CACHE_DIR};
+use crate::state::STATE_DIR;
+use anyhow::{bail, Context, Result};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+/// The site asks that private leaderboards are fetched at most once every 15 minutes.
+pub const REFRESH_INTERVAL: Duration = Duration::from_secs(15 * 60);
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Star {
+    pub get_star_ts: u64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Member {
+    pub id: u64,
+    pub name: Option<String>,
+    #[serde(default)]
+    pub local_score: u64,
+    #[serde(default)]
+    pub stars: u32,
+    /// Stars per day and part, keyed by their numbers as strings
+    #[serde(default)]
+    pub completion_day_level: BTreeMap<String, BTreeMap<String, Star>>,
+}
+
+impl Member {
+    /// The display name, which is absent for anonymous users.
+    pub fn display_name(&self) -> String {
+        self.name
+            .clone()
+            .unwrap_or_else(|| format!("(anonymous user #{})", self.id))
+    }
+
+    /// When the member earned the star of a day's part.
+    pub fn star(&self, day: u8, part: u8) -> Option<u64> {
+        self.completion_day_level
+            .get(&day.to_string())?
+            .get(&part.to_string())
+            .map(|star| star.get_star_ts)
+    }
+
+    /// The member's stars for every day, `*` for both parts, `+` for one and `.` for none.
+    pub fn calendar(&self) -> String {
+        (1..=25)
+            .map(|day| match (self.star(day, 1), self.star(day, 2)) {
+                (Some(_), Some(_)) => '*',
+                (Some(_), None) => '+',
+                _ => '.',
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Leaderboard {
+    pub event: String,
+    pub members: BTreeMap<String, Member>,
+}
+
+impl Leaderboard {
+    pub fn parse(json: &str) -> Result<Self> {
+        serde_json::from_str(json).context("Failed to parse the leaderboard JSON")
+    }
+
+    pub fn year(&self) -> Option<u16> {
+        self.event.parse().ok()
+    }
+
+    /// Members by descending local score, ties broken by stars and then name.
+    pub fn rankings(&self) -> Vec<&Member> {
+        let mut members: Vec<&Member> = self.members.values().collect();
+        members.sort_by(|a, b| {
+            b.local_score
+                .cmp(&a.local_score)
+                .then(b.stars.cmp(&a.stars))
+                .then(a.display_name().cmp(&b.display_name()))
+        });
+        members
+    }
+
+    /// How long after the puzzle unlocked a member earned a star.
+    pub fn star_elapsed(&self, member: &Member, day: u8, part: u8) -> Option<u64> {
+        let unlocked = unlock_time(self.year()?, day);
+        Some(member.star(day, part)?.saturating_sub(unlocked))
+    }
+
+    /// The rankings as text lines, with the star times of `day` when given.
+    pub fn render(&self, day: Option<u8>) -> Vec<String> {
+        let mut header = format!(
+            "{:>4} {:>5}  {:<25}",
+            "", "Score", "1234567890123456789012345"
+        );
+        if let Some(day) = day {
+            header.push_str(&format!("  {:>11} {:>11}", format!("Day {} p1", day), "p2"));
+        }
+        header.push_str("  Name");
+
+        let mut lines = vec![header];
+        for (rank, member) in self.rankings().into_iter().enumerate() {
+            let mut line = format!(
+                "{:>3}) {:>5}  {}",
+                rank + 1,
+                member.local_score,
+                member.calendar()
+            );
+            if let Some(day) = day {
+                let [p1, p2] = [1, 2].map(|part| {
+                    self.star_elapsed(member, day, part)
+                        .map_or_else(|| "-".to_string(), format_elapsed)
+                });
+                line.push_str(&format!("  {:>11} {:>11}", p1, p2));
+            }
+            line.push_str(&format!("  {}", member.display_name()));
+            lines.push(line);
+        }
+        lines
+    }
+}
+
+/// Refuse anything but the number the website uses as a leaderboard id, as the id
+/// becomes part of a URL and a file name.
+pub fn validate_id(id: &str) -> Result<()> {
+    if id.is_empty() || !id.bytes().all(|b| b.is_ascii_digit()) {
+        bail!("Leaderboard id {:?} should be a number, like 123456", id);
+    }
+    Ok(())
+}
+
+/// Path of the cached leaderboard `id` of the event `year`, relative to the workspace root.
+pub fn cache_path(year: u16, id: &str) -> PathBuf {
+    Path::new(STATE_DIR)
+        .join(CACHE_DIR)
+        .join(format!("leaderboard{}-{}.json", year, id))
+}
+
+/// Where a leaderboard was read from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Source {
+    Downloaded,
+    /// The cache, with its age
+    Cached(Duration),
+}
+
+/// Load a private leaderboard, downloading it only when the cache is older than
+/// [`REFRESH_INTERVAL`]. If the download fails, an outdated cache is used instead.
+pub fn load(root: &Path, client: &Client, year: u16, id: &str) -> Result<(Leaderboard, Source)> {
+    validate_id(id)?;
+    let path = root.join(cache_path(year, id));
+    let age = fs::metadata(&path)
+        .and_then(|meta| meta.modified())
+        .ok()
+        .map(|modified| {
+            SystemTime::now()
+                .duration_since(modified)
+                .unwrap_or_default()
+        });
+
+    if let Some(age) = age.filter(|age| *age < REFRESH_INTERVAL) {
+        let json = fs::read_to_string(&path)?;
+        return Ok((Leaderboard::parse(&json)?, Source::Cached(age)));
+    }
+
+    match client.leaderboard(year, id) {
+        Ok(json) => {
+            let leaderboard = Leaderboard::parse(&json)?;
+            fs::create_dir_all(path.parent().unwrap())?;
+            fs::write(&path, &json).with_context(|| format!("Failed to write {:?}", path))?;
+            Ok((leaderboard, Source::Downloaded))
+        }
+        Err(e) => match age {
+            Some(age) => {
+                let json = fs::read_to_string(&path)?;
+                Ok((Leaderboard::parse(&json)?, Source::Cached(age)))
+            }
+            None => Err(e),
+        },
+    }
+}
+
+/// Load a leaderboard from a local JSON file, e.g. one saved from the website.
+pub fn load_file(path: &Path) -> Result<Leaderboard> {
+    let json = fs::read_to_string(path).with_context(|| format!("Failed to read {:?}", path))?;
+    Leaderboard::parse(&json)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc::fixture;
+    use tempfile::TempDir;
+
+    const JSON: &str = r#"{
+        "event": "2024",
+        "owner_id": 1,
+        "members": {
+            "1": {
+                "id": 1, "name": "Ada", "local_score": 10, "stars": 3, "global_score": 0,
+                "last_star_ts": 1733029800,
+                "completion_day_level": {
+                    "1": {
+                        "1": {"get_star_ts": 1733029500, "star_index": 1},
+                        "2": {"get_star_ts": 1733029800, "star_index": 2}
+                    },
+                    "2": {"1": {"get_star_ts": 1733116000, "star_index": 3}}
+                }
+            },
+            "2": {
+                "id": 2, "name": null, "local_score": 12, "stars": 2, "global_score": 0,
+                "last_star_ts": 1733029700,
+                "completion_day_level": {
+                    "1": {
+                        "1": {"get_star_ts": 1733029300, "star_index": 0},
+                        "2": {"get_star_ts": 1733029700, "star_index": 4}
+                    }
+                }
+            }
+        }
+    }"#;
+
+    #[test]
+    fn test_rankings() -> Result<()> {
+        let leaderboard = Leaderboard::parse(JSON)?;
+        let rankings = leaderboard.rankings();
+        assert_eq!(rankings[0].display_name(), "(anonymous user #2)");
+        assert_eq!(rankings[1].calendar(), format!("*+{}", ".".repeat(23)));
+        assert_eq!(leaderboard.star_elapsed(rankings[1], 1, 2), Some(600));
+        assert_eq!(leaderboard.star_elapsed(rankings[1], 2, 2), None);
+
+        let lines = leaderboard.render(Some(1));
+        assert_eq!(lines.len(), 3);
+        assert!(lines[2].starts_with("  2)    10  *+"), "{}", lines[2]);
+        assert!(
+            lines[2].ends_with("   0:05:00     0:10:00  Ada"),
+            "{}",
+            lines[2]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_load_respects_refresh_interval() -> Result<()> {
+        let dir = TempDir::new()?;
+        let (url, requests) = fixture::serve(|_, _, _| (200, JSON.to_string()));
+        let client = Client::new(&url, Some("abc".into()));
+
+        let (_, source) = load(dir.path(), &client, 2024, "1")?;
+        assert_eq!(source, Source::Downloaded);
+        assert_eq!(requests.recv()?, "/2024/leaderboard/private/view/1.json");
+
+        let (leaderboard, source) = load(dir.path(), &client, 2024, "1")?;
+        assert!(matches!(source, Source::Cached(_)));
+        assert_eq!(leaderboard.members.len(), 2);
+        assert!(requests.try_recv().is_err());
+
+        // Another event has a cache of its own
+        load(dir.path(), &client, 2023, "1")?;
+        assert_eq!(requests.recv()?, "/2023/leaderboard/private/view/1.json");
+
+        assert!(load(dir.path(), &client, 2024, "../1").is_err());
+        assert!(validate_id("").is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,238 @@
+// src/aoc/mod.rs
+pub mod examples;
+pub mod leaderboard;
+pub mod puzzle;
+pub mod submit;
+
+use crate::config::Config;
+use crate::state::STATE_DIR;
+use anyhow::{bail, Context, Result};
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
+/// Identifies the tool to the Advent of Code servers, as their automation guidelines ask.
+const USER_AGENT: &str = "github.com/alexjbuck/aocr by alexjbuck@gmail.com";
+
+/// Environment variable holding the session cookie.
+pub const SESSION_ENV: &str = "AOC_SESSION";
+
+/// File holding the session cookie when the environment variable is unset.
+pub const SESSION_FILE: &str = "session";
+
+/// Directory for downloaded pages that must not be committed.
+pub const CACHE_DIR: &str = "cache";
+
+/// A minimal blocking client for the Advent of Code website.
+pub struct Client {
+    agent: ureq::Agent,
+    base_url: String,
+    session: Option<String>,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: Option<String>) -> Self {
+        Self {
+            agent: ureq::AgentBuilder::new()
+                .user_agent(USER_AGENT)
+                .timeout(Duration::from_secs(30))
+                .build(),
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session,
+        }
+    }
+
+    /// A client for the configured site, logged in if a session cookie is available.
+    pub fn from_config(root: &Path, config: &Config) -> Self {
+        Self::new(&config.aoc.base_url, session(root))
+    }
+
+    fn request(&self, method: &str, path: &str) -> (String, ureq::Request) {
+        let url = format!("{}{}", self.base_url, path);
+        let mut request = self.agent.request(method, &url);
+        if let Some(session) = &self.session {
+            request = request.set("Cookie", &format!("session={}", session));
+        }
+        (url, request)
+    }
+
+    fn read(url: &str, result: Result<ureq::Response, ureq::Error>) -> Result<String> {
+        match result {
+            Ok(response) => response
+                .into_string()
+                .with_context(|| format!("Failed to read response from {}", url)),
+            Err(ureq::Error::Status(code, _)) => {
+                bail!("{} responded with HTTP {}", url, code)
+            }
+            Err(e) => Err(e).with_context(|| format!("Failed to fetch {}", url)),
+        }
+    }
+
+    fn get(&self, path: &str) -> Result<String> {
+        let (url, request) = self.request("GET", path);
+        Self::read(&url, request.call())
+    }
+
+    fn post_form(&self, path: &str, form: &[(&str, &str)]) -> Result<String> {
+        let (url, request) = self.request("POST", path);
+        Self::read(&url, request.send_form(form))
+    }
+
+    /// The HTML page of a day's puzzle.
+    pub fn puzzle_page(&self, year: u16, day: u8) -> Result<String> {
+        self.get(&format!("/{}/day/{}", year, day))
+    }
+
+    /// The JSON of a private leaderboard, which requires a session.
+    pub fn leaderboard(&self, year: u16, id: &str) -> Result<String> {
+        self.get(&format!("/{}/leaderboard/private/view/{}.json", year, id))
+    }
+
+    /// Submit an answer, returning the HTML page with the verdict.
+    pub fn submit_answer(&self, year: u16, day: u8, part: u8, answer: &str) -> Result<String> {
+        if self.session.is_none() {
+            bail!(
+                "Submitting needs your session cookie in ${} or {}/{}",
+                SESSION_ENV,
+                STATE_DIR,
+                SESSION_FILE
+            );
+        }
+        self.post_form(
+            &format!("/{}/day/{}/answer", year, day),
+            &[("level", &part.to_string()), ("answer", answer)],
+        )
+    }
+}
+
+/// Days from 1970-01-01 to the given date in the proleptic Gregorian calendar.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let doy = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+/// Unix timestamp at which a day's puzzle unlocks: midnight US Eastern (UTC-5) on December `day`.
+pub fn unlock_time(year: u16, day: u8) -> u64 {
+    let days = days_from_civil(year as i64, 12, day as i64);
+    (days * 86_400 + 5 * 3_600) as u64
+}
+
+/// Format a duration in whole seconds as `h:mm:ss`, or `Nd h:mm:ss` past a day.
+pub fn format_elapsed(seconds: u64) -> String {
+    let (days, rest) = (seconds / 86_400, seconds % 86_400);
+    let clock = format!("{}:{:02}:{:02}", rest / 3_600, rest % 3_600 / 60, rest % 60);
+    if days > 0 {
+        format!("{}d {}", days, clock)
+    } else {
+        clock
+    }
+}
+
+/// The session cookie from `$AOC_SESSION` or `.aocr/session`.
+pub fn session(root: &Path) -> Option<String> {
+    std::env::var(SESSION_ENV)
+        .ok()
+        .or_else(|| fs::read_to_string(root.join(STATE_DIR).join(SESSION_FILE)).ok())
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+}
+
+/// Serve canned HTTP responses on a local port, for tests of the client.
+#[cfg(test)]
+pub mod fixture {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::sync::mpsc;
+    use std::thread;
+
+    /// Start a server answering each request with `handler(path, headers, body)`.
+    ///
+    /// Returns the base URL and a receiver of the requested paths.
+    pub fn serve<F>(handler: F) -> (String, mpsc::Receiver<String>)
+    where
+        F: Fn(&str, &[String], &str) -> (u16, String) + Send + 'static,
+    {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let (tx, rx) = mpsc::channel();
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let Ok(mut stream) = stream else { break };
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut request_line = String::new();
+                reader.read_line(&mut request_line).unwrap();
+                let path = request_line
+                    .split_whitespace()
+                    .nth(1)
+                    .unwrap_or_default()
+                    .to_string();
+                let mut headers = Vec::new();
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if line.trim().is_empty() {
+                        break;
+                    }
+                    headers.push(line.trim().to_string());
+                }
+                let length = headers
+                    .iter()
+                    .find_map(|h| {
+                        h.to_ascii_lowercase()
+                            .strip_prefix("content-length:")
+                            .map(|n| n.trim().parse().unwrap_or(0))
+                    })
+                    .unwrap_or(0);
+                let mut body = vec![0; length];
+                reader.read_exact(&mut body).unwrap();
+                let (status, body) = handler(&path, &headers, &String::from_utf8_lossy(&body));
+                let _ = tx.send(path);
+                let _ = write!(
+                    stream,
+                    "HTTP/1.1 {} OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                );
+            }
+        });
+        (url, rx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_puzzle_page_sends_session() -> Result<()> {
+        let (url, requests) = fixture::serve(|path, headers, _| {
+            let logged_in = headers.iter().any(|h| h == "Cookie: session=abc123");
+            match path {
+                "/2024/day/7" if logged_in => (200, "<article>puzzle</article>".into()),
+                _ => (404, String::new()),
+            }
+        });
+
+        let client = Client::new(&format!("{}/", url), Some("abc123".into()));
+        assert_eq!(client.puzzle_page(2024, 7)?, "<article>puzzle</article>");
+        assert_eq!(requests.recv()?, "/2024/day/7");
+
+        let err = client.puzzle_page(2024, 8).unwrap_err().to_string();
+        assert!(err.contains("HTTP 404"), "{}", err);
+        Ok(())
+    }
+
+    #[test]
+    fn test_unlock_time() {
+        // 2024-12-01T05:00:00Z
+        assert_eq!(unlock_time(2024, 1), 1_733_029_200);
+        assert_eq!(unlock_time(2024, 25) - unlock_time(2024, 1), 24 * 86_400);
+        assert_eq!(format_elapsed(3_723), "1:02:03");
+        assert_eq!(format_elapsed(90_000), "1d 1:00:00");
+    }
+}
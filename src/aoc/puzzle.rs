@@ -0,0 +1,390 @@
+// src/aoc/puzzle.rs
+use super::{Client, CACHE_DIR};
+use crate::state::STATE_DIR;
+use anyhow::{Context, Result};
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// How a run of text inside a block is emphasized.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Style {
+    pub code: bool,
+    pub em: bool,
+    pub link: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Span {
+    pub text: String,
+    pub style: Style,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Block {
+    Heading(String),
+    Paragraph(Vec<Span>),
+    /// A `<pre><code>` block, with whitespace preserved
+    Code(Vec<Span>),
+    ListItem(Vec<Span>),
+}
+
+impl Block {
+    /// The block's text without styling.
+    pub fn text(&self) -> String {
+        match self {
+            Block::Heading(text) => text.clone(),
+            Block::Paragraph(spans) | Block::Code(spans) | Block::ListItem(spans) => {
+                spans.iter().map(|s| s.text.as_str()).collect()
+            }
+        }
+    }
+}
+
+/// The description of a day's puzzle, one entry per unlocked part.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Puzzle {
+    pub parts: Vec<Vec<Block>>,
+}
+
+/// Path of the cached puzzle page for `day`, relative to the workspace root.
+pub fn cache_path(day: u8) -> PathBuf {
+    Path::new(STATE_DIR)
+        .join(CACHE_DIR)
+        .join(format!("puzzle{:02}.html", day))
+}
+
+/// Load a day's puzzle from the cache, fetching it first if missing or `refresh` is set.
+pub fn load(root: &Path, client: &Client, year: u16, day: u8, refresh: bool) -> Result<Puzzle> {
+    let path = root.join(cache_path(day));
+    if !refresh {
+        if let Some(puzzle) = load_cached(root, day)? {
+            return Ok(puzzle);
+        }
+    }
+    let html = client.puzzle_page(year, day)?;
+    let puzzle = parse(&html);
+    if puzzle.parts.is_empty() {
+        anyhow::bail!("The page for day {} contains no puzzle description", day);
+    }
+    fs::create_dir_all(path.parent().unwrap())?;
+    fs::write(&path, &html).with_context(|| format!("Failed to write {:?}", path))?;
+    Ok(puzzle)
+}
+
+/// Load a day's puzzle from the cache only.
+pub fn load_cached(root: &Path, day: u8) -> Result<Option<Puzzle>> {
+    let path = root.join(cache_path(day));
+    match fs::read_to_string(&path) {
+        Ok(html) => Ok(Some(parse(&html))),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(e).with_context(|| format!("Failed to read {:?}", path)),
+    }
+}
+
+/// Accumulates the blocks of one `<article>`.
+#[derive(Default)]
+struct Builder {
+    blocks: Vec<Block>,
+    current: Option<Block>,
+    style: Style,
+    in_pre: bool,
+}
+
+impl Builder {
+    fn start(&mut self, block: Block) {
+        self.finish();
+        self.current = Some(block);
+    }
+
+    fn finish(&mut self) {
+        if let Some(mut block) = self.current.take() {
+            if let Block::Paragraph(spans) | Block::ListItem(spans) = &mut block {
+                trim_spans(spans);
+            }
+            if !block.text().trim().is_empty() {
+                self.blocks.push(block);
+            }
+        }
+    }
+
+    fn text(&mut self, text: &str) {
+        let text = if self.in_pre {
+            text.to_string()
+        } else {
+            collapse_whitespace(text)
+        };
+        if text.is_empty() {
+            return;
+        }
+        let style = self.style;
+        let block = self.current.get_or_insert_with(|| {
+            // Stray text between blocks becomes its own paragraph
+            Block::Paragraph(Vec::new())
+        });
+        match block {
+            Block::Heading(heading) => heading.push_str(&text),
+            Block::Paragraph(spans) | Block::Code(spans) | Block::ListItem(spans) => {
+                match spans.last_mut() {
+                    Some(last) if last.style == style => last.text.push_str(&text),
+                    _ => spans.push(Span { text, style }),
+                }
+            }
+        }
+    }
+}
+
+fn collapse_whitespace(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut in_space = false;
+    for c in text.chars() {
+        if c.is_whitespace() {
+            if !in_space {
+                out.push(' ');
+            }
+            in_space = true;
+        } else {
+            out.push(c);
+            in_space = false;
+        }
+    }
+    out
+}
+
+/// Drop the whitespace HTML would not render at the edges of a block.
+fn trim_spans(spans: &mut Vec<Span>) {
+    if let Some(first) = spans.first_mut() {
+        first.text = first.text.trim_start().to_string();
+    }
+    if let Some(last) = spans.last_mut() {
+        last.text = last.text.trim_end().to_string();
+    }
+    spans.retain(|s| !s.text.is_empty());
+}
+
+/// Decode the HTML entities used on the Advent of Code site.
+pub fn decode_entities(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        out.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let Some(end) = rest.find(';').filter(|&end| end <= 10) else {
+            out.push('&');
+            rest = &rest[1..];
+            continue;
+        };
+        let entity = &rest[1..end];
+        let decoded = match entity {
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "amp" => Some('&'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            "nbsp" => Some('\u{a0}'),
+            _ => entity
+                .strip_prefix("#x")
+                .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+                .or_else(|| entity.strip_prefix('#').and_then(|dec| dec.parse().ok()))
+                .and_then(char::from_u32),
+        };
+        match decoded {
+            Some(c) => {
+                out.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                out.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Parse the `<article>` elements of a puzzle page.
+pub fn parse(html: &str) -> Puzzle {
+    let mut puzzle = Puzzle::default();
+    let mut builder: Option<Builder> = None;
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        let Some(open) = rest.find('<') else {
+            if let Some(b) = builder.as_mut() {
+                b.text(&decode_entities(rest));
+            }
+            break;
+        };
+        if open > 0 {
+            if let Some(b) = builder.as_mut() {
+                b.text(&decode_entities(&rest[..open]));
+            }
+        }
+        rest = &rest[open..];
+        let Some(close) = rest.find('>') else { break };
+        let tag = &rest[1..close];
+        rest = &rest[close + 1..];
+
+        let closing = tag.starts_with('/');
+        let name = tag
+            .trim_start_matches('/')
+            .split(|c: char| c.is_whitespace() || c == '/')
+            .next()
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+
+        if name == "article" {
+            if closing {
+                if let Some(mut b) = builder.take() {
+                    b.finish();
+                    puzzle.parts.push(b.blocks);
+                }
+            } else {
+                builder = Some(Builder::default());
+            }
+            continue;
+        }
+        let Some(b) = builder.as_mut() else { continue };
+
+        match (name.as_str(), closing) {
+            ("h2", false) => b.start(Block::Heading(String::new())),
+            ("p", false) => b.start(Block::Paragraph(Vec::new())),
+            ("li", false) => b.start(Block::ListItem(Vec::new())),
+            ("pre", false) => {
+                b.start(Block::Code(Vec::new()));
+                b.in_pre = true;
+            }
+            ("pre", true) => {
+                b.in_pre = false;
+                b.finish();
+            }
+            ("h2" | "p" | "li", true) => b.finish(),
+            ("code", _) if !b.in_pre => b.style.code = !closing,
+            ("em", _) => b.style.em = !closing,
+            ("a", _) => b.style.link = !closing,
+            ("br", _) => b.text(if b.in_pre { "\n" } else { " " }),
+            _ => {}
+        }
+    }
+
+    puzzle
+}
+
+impl fmt::Display for Puzzle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, blocks) in self.parts.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            for block in blocks {
+                match block {
+                    Block::Heading(text) => writeln!(f, "{}\n", text)?,
+                    Block::Paragraph(_) => writeln!(f, "{}\n", block.text())?,
+                    Block::ListItem(_) => writeln!(f, "  - {}", block.text())?,
+                    Block::Code(_) => {
+                        for line in block.text().trim_end_matches('\n').lines() {
+                            writeln!(f, "    {}", line)?;
+                        }
+                        writeln!(f)?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::aoc::fixture;
+    use tempfile::TempDir;
+
+    pub const PAGE: &str = r#"<!DOCTYPE html>
+<html><body><main>
+<article class="day-desc"><h2>--- Day 1: Historian Hysteria ---</h2>
+<p>Maybe the lists are only off by a small amount! To find out, pair up the numbers.</p>
+<p>For example:</p>
+<pre><code>3   4
+4   3
+2   5
+</code></pre>
+<ul>
+<li>The smallest number in the left list is <code>1</code>.</li>
+</ul>
+<p>In the example above, this is <code>2 + 1 + 0 + 1 + 2 + 5</code>, a total distance of <code><em>11</em></code>!</p>
+<p>Your actual left &amp; right lists contain many location IDs. <em>What is the total distance?</em></p>
+</article>
+<p>Your puzzle answer was <code>1234</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<p>For example, again:</p>
+<pre><code>1 &lt; 2
+</code></pre>
+<p>So, the similarity score is <code><em>31</em></code>.</p>
+</article>
+</main></body></html>
+"#;
+
+    #[test]
+    fn test_parse_puzzle() {
+        let puzzle = parse(PAGE);
+        assert_eq!(puzzle.parts.len(), 2);
+
+        let part1 = &puzzle.parts[0];
+        assert_eq!(
+            part1[0],
+            Block::Heading("--- Day 1: Historian Hysteria ---".into())
+        );
+        assert_eq!(part1[2].text(), "For example:");
+        assert_eq!(
+            part1[3],
+            Block::Code(vec![Span {
+                text: "3   4\n4   3\n2   5\n".into(),
+                style: Style::default(),
+            }])
+        );
+        assert!(matches!(&part1[4], Block::ListItem(_)));
+        let Block::Paragraph(spans) = &part1[5] else {
+            panic!("expected a paragraph")
+        };
+        assert_eq!(
+            spans.iter().rev().nth(1),
+            Some(&Span {
+                text: "11".into(),
+                style: Style {
+                    code: true,
+                    em: true,
+                    link: false
+                }
+            })
+        );
+        assert!(part1[6].text().contains("left & right"));
+
+        assert_eq!(puzzle.parts[1][2].text(), "1 < 2\n");
+        assert!(puzzle.to_string().contains("    3   4\n"));
+    }
+
+    #[test]
+    fn test_decode_entities() {
+        assert_eq!(decode_entities("a &lt;b&gt; &amp;&#39;&#x41;"), "a <b> &'A");
+        assert_eq!(decode_entities("AT&T; &unknown;"), "AT&T; &unknown;");
+    }
+
+    #[test]
+    fn test_load_caches_page() -> Result<()> {
+        let dir = TempDir::new()?;
+        let (url, requests) = fixture::serve(|_, _, _| (200, PAGE.to_string()));
+        let client = Client::new(&url, None);
+
+        assert_eq!(load_cached(dir.path(), 1)?, None);
+        let puzzle = load(dir.path(), &client, 2024, 1, false)?;
+        assert_eq!(requests.recv()?, "/2024/day/1");
+
+        // The second load is served from the cache
+        assert_eq!(load(dir.path(), &client, 2024, 1, false)?, puzzle);
+        assert!(requests.try_recv().is_err());
+        assert!(dir.path().join(cache_path(1)).exists());
+        Ok(())
+    }
+}
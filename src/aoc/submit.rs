@@ -0,0 +1,182 @@
+// src/aoc/submit.rs
+use super::{puzzle, Client};
+use crate::state::{self, DayState};
+use anyhow::{bail, Result};
+use std::fmt;
+use std::path::Path;
+
+/// What the website said about a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    /// The answer the website accepted before, so it was not sent again
+    AlreadyAccepted,
+    /// With the site's hint, e.g. "your answer is too high"
+    Wrong(Option<String>),
+    /// With how long to wait, e.g. "You have 37s left to wait"
+    TooSoon(String),
+    /// The part was solved already, or part two is still locked
+    WrongLevel,
+    /// A response aocr does not recognize, as plain text
+    Unknown(String),
+}
+
+impl Verdict {
+    /// Read the verdict from the page returned by a submission.
+    pub fn parse(html: &str) -> Self {
+        let text = puzzle::parse(html)
+            .parts
+            .iter()
+            .flatten()
+            .map(|block| block.text())
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        if text.contains("That's the right answer") {
+            Verdict::Correct
+        } else if text.contains("That's not the right answer") {
+            let hint = ["too high", "too low"]
+                .into_iter()
+                .find(|hint| text.contains(hint))
+                .map(|hint| format!("your answer is {}", hint));
+            Verdict::Wrong(hint)
+        } else if text.contains("You gave an answer too recently") {
+            let wait = text
+                .find("You have ")
+                .map(|start| {
+                    let rest = &text[start..];
+                    rest[..rest.find('.').unwrap_or(rest.len())].to_string()
+                })
+                .unwrap_or_default();
+            Verdict::TooSoon(wait)
+        } else if text.contains("You don't seem to be solving the right level") {
+            Verdict::WrongLevel
+        } else {
+            Verdict::Unknown(text)
+        }
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "That's the right answer!"),
+            Verdict::AlreadyAccepted => write!(f, "This answer was already accepted"),
+            Verdict::Wrong(Some(hint)) => write!(f, "That's not the right answer; {}", hint),
+            Verdict::Wrong(None) => write!(f, "That's not the right answer"),
+            Verdict::TooSoon(wait) => write!(f, "Answered too recently. {}", wait),
+            Verdict::WrongLevel => write!(f, "This part is already solved or not unlocked yet"),
+            Verdict::Unknown(text) => write!(f, "Unexpected response: {}", text),
+        }
+    }
+}
+
+/// Submit an answer and record the outcome in the day's state.
+///
+/// Answers that were already rejected are refused, and the accepted one is reported as
+/// such, without contacting the website.
+pub fn submit(
+    root: &Path,
+    client: &Client,
+    year: u16,
+    day: u8,
+    part: u8,
+    answer: &str,
+) -> Result<Verdict> {
+    let mut day_state = DayState::load(root, day)?;
+    let part_state = day_state.part_mut(part);
+    if part_state.wrong_answers.iter().any(|wrong| wrong == answer) {
+        bail!(
+            "{} was already rejected for day {} part {}",
+            answer,
+            day,
+            part
+        );
+    }
+    if part_state.answer.as_deref() == Some(answer) {
+        return Ok(Verdict::AlreadyAccepted);
+    }
+
+    let verdict = Verdict::parse(&client.submit_answer(year, day, part, answer)?);
+    match verdict {
+        Verdict::Correct => {
+            part_state.completed = true;
+            part_state.answer = Some(answer.to_string());
+            part_state.solved_at.get_or_insert_with(state::now);
+        }
+        Verdict::Wrong(_) => part_state.wrong_answers.push(answer.to_string()),
+        _ => return Ok(verdict),
+    }
+    day_state.save(root, day)?;
+    Ok(verdict)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc::fixture;
+    use tempfile::TempDir;
+
+    fn page(message: &str) -> String {
+        format!("<main><article><p>{}</p></article></main>", message)
+    }
+
+    #[test]
+    fn test_parse_verdicts() {
+        assert_eq!(
+            Verdict::parse(&page(
+                "That's the right answer! You are one gold star closer."
+            )),
+            Verdict::Correct
+        );
+        assert_eq!(
+            Verdict::parse(&page(
+                "That's not the right answer; your answer is too low. Please wait one minute."
+            )),
+            Verdict::Wrong(Some("your answer is too low".into()))
+        );
+        assert_eq!(
+            Verdict::parse(&page(
+                "You gave an answer too recently; you have to wait. You have 37s left to wait. [Return]"
+            )),
+            Verdict::TooSoon("You have 37s left to wait".into())
+        );
+    }
+
+    #[test]
+    fn test_submit_records_attempts() -> Result<()> {
+        let dir = TempDir::new()?;
+        let (url, requests) = fixture::serve(|_, _, body| {
+            if body.contains("answer=42") {
+                (200, page("That's the right answer!"))
+            } else {
+                (200, page("That's not the right answer."))
+            }
+        });
+        let client = Client::new(&url, Some("abc".into()));
+
+        assert_eq!(
+            submit(dir.path(), &client, 2024, 3, 1, "41")?,
+            Verdict::Wrong(None)
+        );
+        assert_eq!(requests.recv()?, "/2024/day/3/answer");
+        assert!(submit(dir.path(), &client, 2024, 3, 1, "41").is_err());
+        assert_eq!(
+            submit(dir.path(), &client, 2024, 3, 1, "42")?,
+            Verdict::Correct
+        );
+        // Submitting it again does not count as a new star
+        assert_eq!(
+            submit(dir.path(), &client, 2024, 3, 1, "42")?,
+            Verdict::AlreadyAccepted
+        );
+        assert_eq!(requests.try_iter().count(), 1);
+
+        let state = DayState::load(dir.path(), 3)?;
+        assert!(state.part1.completed);
+        assert_eq!(state.part1.answer.as_deref(), Some("42"));
+        assert_eq!(state.part1.wrong_answers, vec!["41".to_string()]);
+        assert!(state.part1.solved_at.is_some());
+        Ok(())
+    }
+}
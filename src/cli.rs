@@ -14,6 +14,62 @@ pub enum Commands {
     Init {
         /// Path where to create the workspace
         path: PathBuf,
+        /// Day crate template: minimal, parse, nom, grid, or a template directory
+        #[arg(short, long, default_value = "minimal")]
+        template: String,
+        /// Advent of Code event year
+        #[arg(short, long, default_value_t = 2024)]
+        year: u16,
+        /// Start without day crates and create them later with `aocr new`
+        #[arg(long)]
+        empty: bool,
+        /// Add a shared `common` library crate that every day depends on
+        #[arg(long)]
+        common: bool,
+        /// Add missing crates and config to an existing workspace, never touching solutions
+        #[arg(long)]
+        upgrade: bool,
+        /// Print what would change, with a diff, without writing anything
+        #[arg(long)]
+        dry_run: bool,
+        /// Do not create a git repository or commit the workspace
+        #[arg(long)]
+        no_git: bool,
+    },
+    /// Create the crate for a day in the current workspace
+    New {
+        /// Day number (1-25)
+        day: u8,
+    },
+    /// Print a day's puzzle description, downloading it if it is not cached
+    Puzzle {
+        /// Day number (1-25)
+        day: u8,
+        /// Download the page again, e.g. after unlocking part two
+        #[arg(long)]
+        refresh: bool,
+    },
+    /// Extract the example inputs and answers from a day's puzzle description
+    Examples {
+        /// Day number (1-25)
+        day: u8,
+        /// Save them as example inputs without asking
+        #[arg(long)]
+        save: bool,
+        /// Add `#[test]` functions checking them to the day's `lib.rs` without asking
+        #[arg(long)]
+        tests: bool,
+    },
+    /// Show the rankings of a private leaderboard
+    Leaderboard {
+        /// Leaderboard id, defaults to `aoc.leaderboard` in aocr.toml
+        id: Option<String>,
+        /// Read the leaderboard JSON from a file instead of the website
+        #[arg(short, long)]
+        file: Option<PathBuf>,
+        /// Also show when each member earned the stars of this day
+        #[arg(short, long)]
+        day: Option<u8>,
     },
     /// Run a specific day and part
     Run {
@@ -24,7 +80,67 @@ pub enum Commands {
         /// Optional input file path
         #[arg(short, long)]
         input: Option<PathBuf>,
+        /// Run a named input such as `alice` instead of your own, saving `--input` under it
+        #[arg(short = 'n', long)]
+        input_name: Option<String>,
+    },
+    /// Submit an answer, by default the latest one `run` computed
+    Submit {
+        /// Day number (1-25)
+        day: u8,
+        /// Part number (1-2)
+        part: u8,
+        /// Answer to submit instead of the latest computed one
+        answer: Option<String>,
+    },
+    /// Show the time from unlock to each star and the wrong attempts per day
+    Stats,
+    /// Run a part and its alternative implementations, such as `part1_fast`, side by side
+    Compare {
+        /// Day number (1-25)
+        day: u8,
+        /// Part number (1-2)
+        part: u8,
+        /// Run a named input such as `alice` instead of your own
+        #[arg(short = 'n', long)]
+        input_name: Option<String>,
+    },
+    /// Run both parts of a day on every stored input and compare with the known answers
+    Crosscheck {
+        /// Day number (1-25)
+        day: u8,
     },
     /// Watch mode with TUI
     Watch,
+    /// Manage the shared `common` crate
+    Common {
+        #[command(subcommand)]
+        command: CommonCommands,
+    },
+    /// Manage how inputs are stored
+    Input {
+        #[command(subcommand)]
+        command: InputCommands,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum CommonCommands {
+    /// Install a curated helper module (grid, graph, parse, memo), creating the crate if needed
+    Add {
+        /// Name of the module
+        module: String,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum InputCommands {
+    /// Keep a day's inputs byte for byte instead of normalizing them
+    Preserve {
+        /// Day number (1-25)
+        day: u8,
+        /// Normalize the day's inputs again
+        #[arg(long)]
+        off: bool,
+    },
 }
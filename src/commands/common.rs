@@ -0,0 +1,148 @@
+// src/commands/common.rs
+use crate::config::Config;
+use crate::template::{COMMON_FILES, COMMON_MODULES};
+use crate::workspace;
+use anyhow::{bail, Result};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Name of the shared crate, also used as its directory and dependency name.
+pub const COMMON_CRATE: &str = "common";
+
+pub fn execute_add(module: &str) -> Result<()> {
+    let root = Path::new(".");
+    if create(root)? {
+        println!("Created the {} crate", COMMON_CRATE);
+    }
+    add_module(root, module)?;
+    println!("Added {}::{}", COMMON_CRATE, module);
+    Ok(())
+}
+
+/// The files of a fresh `common` crate, relative to its directory.
+pub fn render(year: u16) -> Vec<(PathBuf, String)> {
+    COMMON_FILES
+        .iter()
+        .map(|(path, contents)| {
+            (
+                PathBuf::from(path),
+                contents.replace("{{year}}", &year.to_string()),
+            )
+        })
+        .collect()
+}
+
+/// Create the `common` crate in the workspace at `root` and make every day depend on it.
+///
+/// Returns `false` if it already exists.
+pub fn create(root: &Path) -> Result<bool> {
+    let crate_path = root.join(COMMON_CRATE);
+    if crate_path.exists() {
+        return Ok(false);
+    }
+
+    let mut config = Config::load(root)?;
+    for (relative, contents) in render(config.year) {
+        let file_path = crate_path.join(relative);
+        if let Some(parent) = file_path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(file_path, contents)?;
+    }
+
+    workspace::add_member(root, COMMON_CRATE)?;
+    workspace::add_workspace_dependency(root, COMMON_CRATE, COMMON_CRATE)?;
+    for day in 1..=25 {
+        let day_path = root.join(format!("day{:02}", day));
+        if day_path.join("Cargo.toml").exists() {
+            workspace::inherit_dependency(&day_path, COMMON_CRATE)?;
+        }
+    }
+
+    config.common = true;
+    config.save(root)?;
+    Ok(true)
+}
+
+/// Install the curated helper `name` as a module of the `common` crate.
+pub fn add_module(root: &Path, name: &str) -> Result<()> {
+    let Some(module) = COMMON_MODULES.iter().find(|m| m.name == name) else {
+        let available: Vec<String> = COMMON_MODULES
+            .iter()
+            .map(|m| format!("  {:<6} {}", m.name, m.description))
+            .collect();
+        bail!(
+            "Unknown module {:?}, available modules are:\n{}",
+            name,
+            available.join("\n")
+        );
+    };
+
+    let src = root.join(COMMON_CRATE).join("src");
+    let module_path = src.join(format!("{}.rs", module.name));
+    if module_path.exists() {
+        bail!("{}::{} is already installed", COMMON_CRATE, module.name);
+    }
+    fs::write(&module_path, module.source)?;
+
+    let lib_path = src.join("lib.rs");
+    let mut lib = fs::read_to_string(&lib_path)?;
+    if !lib.ends_with('\n') {
+        lib.push('\n');
+    }
+    // Keep module declarations separate from the crate docs
+    if lib
+        .lines()
+        .last()
+        .is_some_and(|line| line.starts_with("//!"))
+    {
+        lib.push('\n');
+    }
+    lib.push_str(&format!("pub mod {};\n", module.name));
+    fs::write(lib_path, lib)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::init::{self, InitOptions};
+    use std::process::Command;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_common_crate_with_all_modules() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        init::execute(
+            temp_dir.path().to_path_buf(),
+            InitOptions {
+                common: true,
+                ..Default::default()
+            },
+        )?;
+        assert!(!create(temp_dir.path())?);
+
+        for module in COMMON_MODULES {
+            add_module(temp_dir.path(), module.name)?;
+        }
+        assert!(add_module(temp_dir.path(), "grid").is_err());
+        assert!(add_module(temp_dir.path(), "not-a-module").is_err());
+
+        let day_manifest = fs::read_to_string(temp_dir.path().join("day01/Cargo.toml"))?;
+        assert!(day_manifest.contains("common.workspace = true"));
+        assert!(Config::load(temp_dir.path())?.common);
+
+        // The helpers and their own tests compile and pass inside the workspace
+        let output = Command::new("cargo")
+            .args(["test", "-p", "common", "-p", "day01"])
+            .current_dir(temp_dir.path())
+            .output()?;
+        assert!(
+            output.status.success(),
+            "{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,95 @@
+// src/commands/compare.rs
+use crate::config::Config;
+use crate::input::{self, check};
+use crate::runner::{Failure, Runner, Solution};
+use anyhow::{bail, Context, Result};
+use std::fs;
+use std::path::Path;
+
+/// The timing table, each function's runtime relative to the original `partN`.
+pub fn render(results: &[(String, Solution)]) -> Vec<String> {
+    let name_width = results
+        .iter()
+        .map(|(name, _)| name.len())
+        .max()
+        .unwrap_or(0)
+        .max(8);
+    let baseline = results[0].1.duration.as_secs_f64();
+    let expected = results[0].1.answer;
+
+    let mut lines = vec![format!(
+        "{:<name_width$}  {:>16}  {:>12}  {:>8}",
+        "Function", "Answer", "Time", "Relative"
+    )];
+    for (name, solution) in results {
+        let relative = if baseline > 0.0 {
+            format!("{:.2}x", solution.duration.as_secs_f64() / baseline)
+        } else {
+            String::from("-")
+        };
+        let mismatch = if solution.answer == expected {
+            ""
+        } else {
+            "  differs"
+        };
+        lines.push(format!(
+            "{:<name_width$}  {:>16}  {:>12}  {:>8}{}",
+            name,
+            solution.answer,
+            format!("{:.1?}", solution.duration),
+            relative,
+            mismatch
+        ));
+    }
+    lines
+}
+
+pub fn execute(day: u8, part: u8, input_name: Option<String>) -> Result<()> {
+    let root = Path::new(".");
+    let runner = Runner::default().with_backtrace(Config::load(root)?.run.backtrace);
+    if let Some(name) = &input_name {
+        input::validate_name(name)?;
+    }
+    let name = input_name.as_deref().unwrap_or(input::DEFAULT_NAME);
+    let path = input::named_path(day, part, name);
+    let raw = fs::read_to_string(&path).with_context(|| format!("Failed to read {:?}", path))?;
+    let checked = check::prepare(root, day, name, &raw)?;
+    for warning in &checked.warnings {
+        eprintln!("Warning: {}", warning);
+    }
+
+    let mut functions = vec![format!("part{}", part)];
+    functions.extend(runner.variants(day, part)?);
+    if functions.len() == 1 {
+        bail!(
+            "day{:02}/src/lib.rs has no alternatives to part{}, name them e.g. `pub fn part{}_fast`",
+            day,
+            part,
+            part
+        );
+    }
+
+    let (solutions, output) = match runner.run_functions(day, &functions, &checked.input) {
+        Ok(run) => run,
+        Err(e) => {
+            if let Some(failure) = e.downcast_ref::<Failure>() {
+                eprint!("{}", failure.render(root, false));
+            }
+            return Err(e);
+        }
+    };
+    print!("{}", output.render());
+    let results: Vec<(String, Solution)> = functions.into_iter().zip(solutions).collect();
+    for line in render(&results) {
+        println!("{}", line);
+    }
+
+    let differing = results
+        .iter()
+        .filter(|(_, solution)| solution.answer != results[0].1.answer)
+        .count();
+    if differing > 0 {
+        bail!("{} implementation(s) disagree with part{}", differing, part);
+    }
+    Ok(())
+}
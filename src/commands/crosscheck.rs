@@ -0,0 +1,178 @@
+// src/commands/crosscheck.rs
+use crate::config::Config;
+use crate::input::{self, check};
+use crate::runner::Runner;
+use crate::state::DayState;
+use anyhow::{bail, Result};
+use std::fs;
+use std::path::Path;
+
+/// How one part did on one input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Check {
+    /// The answer matches the known one
+    Pass(String),
+    Fail {
+        answer: String,
+        expected: String,
+    },
+    /// No known answer to compare with
+    Unknown(String),
+    Error(String),
+    /// The part has no input of this name
+    Missing,
+}
+
+impl Check {
+    pub fn judge(answer: String, expected: Option<&str>) -> Self {
+        match expected {
+            Some(expected) if expected == answer => Check::Pass(answer),
+            Some(expected) => Check::Fail {
+                answer,
+                expected: expected.to_string(),
+            },
+            None => Check::Unknown(answer),
+        }
+    }
+
+    fn cell(&self) -> String {
+        match self {
+            Check::Pass(answer) => format!("pass {}", answer),
+            Check::Fail { answer, expected } => format!("FAIL {} (want {})", answer, expected),
+            Check::Unknown(answer) => format!("?    {}", answer),
+            Check::Error(_) => String::from("error"),
+            Check::Missing => String::from("-"),
+        }
+    }
+}
+
+/// The matrix of inputs by part, one row per input.
+pub fn render(rows: &[(String, [Check; 2])]) -> Vec<String> {
+    let name_width = rows
+        .iter()
+        .map(|(name, _)| name.len())
+        .chain([5])
+        .max()
+        .unwrap_or(5);
+    let cell_width = rows
+        .iter()
+        .flat_map(|(_, checks)| checks.iter().map(|check| check.cell().len()))
+        .chain([6])
+        .max()
+        .unwrap_or(6);
+
+    let mut lines = vec![format!(
+        "{:<name_width$}  {:<cell_width$}  Part 2",
+        "Input", "Part 1"
+    )];
+    for (name, [part1, part2]) in rows {
+        lines.push(
+            format!(
+                "{:<name_width$}  {:<cell_width$}  {}",
+                name,
+                part1.cell(),
+                part2.cell()
+            )
+            .trim_end()
+            .to_string(),
+        );
+    }
+    lines
+}
+
+pub fn execute(day: u8) -> Result<()> {
+    let root = Path::new(".");
+    let runner = Runner::default().with_backtrace(Config::load(root)?.run.backtrace);
+    let state = DayState::load(root, day)?;
+
+    let mut names = input::names(root, day, 1);
+    for name in input::names(root, day, 2) {
+        if !names.contains(&name) {
+            names.push(name);
+        }
+    }
+
+    let mut rows = Vec::new();
+    for name in names {
+        let checks = [1, 2].map(|part| {
+            let Ok(raw) = fs::read_to_string(root.join(input::named_path(day, part, &name))) else {
+                return Check::Missing;
+            };
+            println!("Running day {} part {} on {}...", day, part, name);
+            let result = check::prepare(root, day, &name, &raw).and_then(|checked| {
+                for warning in &checked.warnings {
+                    eprintln!("Warning: {}", warning);
+                }
+                runner.run_day(day, part, &checked.input)
+            });
+            match result {
+                Ok((solution, output)) => {
+                    print!("{}", output.render());
+                    Check::judge(
+                        solution.answer.to_string(),
+                        state.part(part).known_answer(&name),
+                    )
+                }
+                Err(e) => Check::Error(format!("{:#}", e)),
+            }
+        });
+        if checks.iter().any(|check| *check != Check::Missing) {
+            rows.push((name, checks));
+        }
+    }
+    if rows.is_empty() {
+        bail!("Day {} has no stored inputs", day);
+    }
+
+    println!();
+    for line in render(&rows) {
+        println!("{}", line);
+    }
+    for (name, checks) in &rows {
+        for (part, check) in (1..=2).zip(checks) {
+            if let Check::Error(e) = check {
+                println!("\nPart {} on {}: {}", part, name, e);
+            }
+        }
+    }
+
+    let failed = rows
+        .iter()
+        .flat_map(|(_, checks)| checks)
+        .filter(|check| matches!(check, Check::Fail { .. } | Check::Error(_)))
+        .count();
+    if failed > 0 {
+        bail!("{} check(s) failed", failed);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_matrix() {
+        let rows = vec![
+            (
+                String::from("mine"),
+                [
+                    Check::judge("42".into(), Some("42")),
+                    Check::judge("7".into(), None),
+                ],
+            ),
+            (
+                String::from("alice"),
+                [Check::judge("41".into(), Some("40")), Check::Missing],
+            ),
+        ];
+        assert_eq!(
+            render(&rows),
+            [
+                "Input  Part 1             Part 2",
+                "mine   pass 42            ?    7",
+                "alice  FAIL 41 (want 40)  -",
+            ]
+        );
+    }
+}
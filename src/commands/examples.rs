@@ -0,0 +1,59 @@
+// src/commands/examples.rs
+use crate::aoc::{self, examples, puzzle};
+use crate::config::Config;
+use anyhow::{bail, Result};
+use dialoguer::Confirm;
+use std::path::Path;
+
+pub fn execute(day: u8, save: bool, tests: bool) -> Result<()> {
+    if !(1..=25).contains(&day) {
+        bail!("Day must be between 1 and 25, got {}", day);
+    }
+    let root = Path::new(".");
+    let config = Config::load(root)?;
+    let client = aoc::Client::from_config(root, &config);
+    let puzzle = puzzle::load(root, &client, config.year, day, false)?;
+
+    let found = examples::extract(&puzzle);
+    if found.is_empty() {
+        bail!("No example found in the description of day {}", day);
+    }
+    for example in &found {
+        println!(
+            "Part {} example (answer: {}):",
+            example.part,
+            example.answer.as_deref().unwrap_or("unknown")
+        );
+        for line in example.input.lines() {
+            println!("    {}", line);
+        }
+        println!();
+    }
+
+    // Ask for whatever was not requested on the command line
+    let interactive = !save && !tests;
+    if save
+        || interactive
+            && Confirm::new()
+                .with_prompt("Save them as example inputs?")
+                .default(true)
+                .interact()?
+    {
+        examples::save(root, day, &found)?;
+        println!("Saved examples to {}/", crate::input::INPUTS_DIR);
+    }
+
+    let lib = root.join(format!("day{:02}/src/lib.rs", day));
+    if tests
+        || interactive
+            && lib.exists()
+            && Confirm::new()
+                .with_prompt(format!("Add example tests to {}?", lib.display()))
+                .default(false)
+                .interact()?
+    {
+        let added = examples::add_tests(&lib, &found)?;
+        println!("Added {} test(s) to {}", added, lib.display());
+    }
+    Ok(())
+}
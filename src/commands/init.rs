@@ -1,40 +1,61 @@
 // src/commands/init.rs
-use anyhow::{Context, Result};
+use super::common::{self, COMMON_CRATE};
+use crate::config::{Config, CONFIG_FILE};
+use crate::git;
+use crate::input;
+use crate::template::{self, Template};
+use crate::workspace;
+use anyhow::{bail, Context, Result};
+use similar::TextDiff;
 use std::fs;
 use std::path::{Path, PathBuf};
-use std::process::Command;
+use toml_edit::DocumentMut;
+
+pub struct InitOptions {
+    /// Built-in template name or path to a template directory
+    pub template: String,
+    /// Advent of Code event year
+    pub year: u16,
+    /// Start without day crates; they are created with `aocr new`
+    pub empty: bool,
+    /// Add a `common` crate that every day depends on
+    pub common: bool,
+    /// Add missing files and migrate an existing workspace
+    pub upgrade: bool,
+    /// Only print what would change
+    pub dry_run: bool,
+    /// Do not create a repository or commit
+    pub no_git: bool,
+}
 
-pub fn execute(path: PathBuf) -> Result<()> {
-    // Create the workspace directory
-    fs::create_dir_all(&path)?;
+impl Default for InitOptions {
+    fn default() -> Self {
+        Self {
+            template: template::DEFAULT_TEMPLATE.to_string(),
+            year: 2024,
+            empty: false,
+            common: false,
+            upgrade: false,
+            dry_run: false,
+            no_git: false,
+        }
+    }
+}
 
-    // Create workspace Cargo.toml with all day crates as members
-    let workspace_toml = r#"[workspace]
-members = [
+const WORKSPACE_MEMBERS: &str = r#"members = [
     "runner",
     "day01", "day02", "day03", "day04", "day05",
     "day06", "day07", "day08", "day09", "day10",
     "day11", "day12", "day13", "day14", "day15",
     "day16", "day17", "day18", "day19", "day20",
     "day21", "day22", "day23", "day24", "day25"
-]
-resolver = "2"
-
-[workspace.dependencies]
-anyhow = "1.0.75"
-"#;
-    fs::write(path.join("Cargo.toml"), workspace_toml)?;
+]"#;
 
-    // Create runner crate
-    create_runner_crate(&path)?;
-
-    // Create all day crates
-    for day in 1..=25 {
-        create_day_crate(&path, day)?;
-    }
+const EMPTY_WORKSPACE_MEMBERS: &str = r#"members = [
+    "runner",
+]"#;
 
-    // Create .gitignore
-    let gitignore = r#"# Generated by Cargo
+const GITIGNORE: &str = r#"# Generated by Cargo
 /target/
 Cargo.lock
 
@@ -49,49 +70,14 @@ Cargo.lock
 
 # Project specific
 /inputs/
+/.aocr/cache/
+/.aocr/session
+/runner/results.txt
+/runner/panic.txt
+/runner/input.txt
 "#;
-    fs::write(path.join(".gitignore"), gitignore)?;
-
-    // Initialize git repository
-    init_git_repo(&path)?;
-
-    println!(
-        "Successfully initialized Advent of Code workspace at {:?}",
-        path
-    );
-    Ok(())
-}
-
-fn init_git_repo(path: &Path) -> Result<()> {
-    // Initialize git repository
-    Command::new("git")
-        .arg("init")
-        .current_dir(path)
-        .output()
-        .context("Failed to initialize git repository")?;
-
-    // Add all files
-    Command::new("git")
-        .args(["add", "."])
-        .current_dir(path)
-        .output()
-        .context("Failed to stage files")?;
-
-    // Initial commit
-    Command::new("git")
-        .args(["commit", "-m", "Initial commit: Advent of Code workspace"])
-        .current_dir(path)
-        .output()
-        .context("Failed to create initial commit")?;
-
-    Ok(())
-}
-
-fn create_runner_crate(workspace_path: &Path) -> Result<()> {
-    let runner_path = workspace_path.join("runner");
-    fs::create_dir_all(runner_path.join("src"))?;
 
-    let runner_toml = r#"[package]
+const RUNNER_TOML: &str = r#"[package]
 name = "runner"
 version = "0.1.0"
 edition = "2021"
@@ -99,82 +85,425 @@ edition = "2021"
 [dependencies]
 anyhow.workspace = true
 "#;
-    fs::write(runner_path.join("Cargo.toml"), runner_toml)?;
 
-    let runner_main = r#"fn main() {
+const RUNNER_MAIN: &str = r#"fn main() {
     let input = include_str!("INPUT_PATH");
     let result = TARGET_CRATE::partN(input);
     println!("Day {} Part {}: {}", DAY, PART, result);
 }
 "#;
-    fs::write(runner_path.join("src").join("main.rs"), runner_main)?;
 
-    Ok(())
+/// A file `init` wants to exist, relative to the workspace root.
+struct PlannedFile {
+    path: PathBuf,
+    contents: String,
+    /// Whether an existing file with different contents may be replaced.
+    ///
+    /// Solution files never are; workspace configuration is merged and then replaced.
+    overwrite: bool,
 }
 
-fn create_day_crate(workspace_path: &Path, day: u8) -> Result<()> {
-    let day_str = format!("day{:02}", day);
-    let day_path = workspace_path.join(&day_str);
-    fs::create_dir_all(day_path.join("src"))?;
+/// What applying a planned file does to the workspace.
+enum Change {
+    Create,
+    Update {
+        old: String,
+    },
+    /// The file exists with different contents and is left alone
+    Keep,
+    Unchanged,
+}
 
-    let day_toml = format!(
-        r#"[package]
-name = "{}"
-version = "0.1.0"
-edition = "2021"
+struct Plan {
+    files: Vec<(PlannedFile, Change)>,
+    /// Files moved from an older layout, as `(from, to)`
+    moves: Vec<(PathBuf, PathBuf)>,
+}
 
-[dependencies]
-anyhow.workspace = true
+impl Plan {
+    /// Whether applying the plan would touch anything.
+    fn has_changes(&self) -> bool {
+        !self.moves.is_empty()
+            || self
+                .files
+                .iter()
+                .any(|(_, change)| matches!(change, Change::Create | Change::Update { .. }))
+    }
 
-[lib]
-name = "{}"
-path = "src/lib.rs"
-"#,
-        day_str, day_str
-    );
-    fs::write(day_path.join("Cargo.toml"), day_toml)?;
+    fn print(&self, show_diff: bool) {
+        for (file, change) in &self.files {
+            match change {
+                Change::Create => println!("create  {}", file.path.display()),
+                Change::Update { old } => {
+                    println!("update  {}", file.path.display());
+                    if show_diff {
+                        let path = file.path.display().to_string();
+                        print!(
+                            "{}",
+                            TextDiff::from_lines(old, &file.contents)
+                                .unified_diff()
+                                .header(&format!("a/{}", path), &format!("b/{}", path))
+                        );
+                    }
+                }
+                Change::Keep => {
+                    println!("keep    {} (exists, not overwritten)", file.path.display())
+                }
+                Change::Unchanged => {}
+            }
+        }
+        for (from, to) in &self.moves {
+            println!("move    {} -> {}", from.display(), to.display());
+        }
+    }
 
-    let day_lib = format!(
-        r#"//! Solution for Advent of Code 2024, Day {}
+    fn apply(&self, root: &Path) -> Result<()> {
+        for (file, change) in &self.files {
+            if matches!(change, Change::Create | Change::Update { .. }) {
+                let path = root.join(&file.path);
+                if let Some(parent) = path.parent() {
+                    fs::create_dir_all(parent)?;
+                }
+                fs::write(&path, &file.contents)
+                    .with_context(|| format!("Failed to write {:?}", path))?;
+            }
+        }
+        for (from, to) in &self.moves {
+            fs::rename(root.join(from), root.join(to))
+                .with_context(|| format!("Failed to move {:?} to {:?}", from, to))?;
+        }
+        Ok(())
+    }
+}
 
-pub fn part1(input: &str) -> usize {{
-    // TODO: Implement part 1 solution
-    0
-}}
+pub fn execute(path: PathBuf, options: InitOptions) -> Result<()> {
+    let existing_workspace = path.join("Cargo.toml").exists();
+
+    // An existing workspace keeps its configuration, new ones use the options
+    let mut config = if path.join(CONFIG_FILE).exists() {
+        Config::load(&path)?
+    } else {
+        let template_spec = match Template::resolve(&options.template)? {
+            Template::Builtin(builtin) => builtin.name.to_string(),
+            Template::Directory(dir) => fs::canonicalize(dir)?.to_string_lossy().into_owned(),
+        };
+        Config {
+            year: options.year,
+            template: template_spec,
+            ..Default::default()
+        }
+    };
+    config.common |= options.common || path.join(COMMON_CRATE).exists();
+    let template = Template::resolve(&config.template)?;
 
-pub fn part2(input: &str) -> usize {{
-    // TODO: Implement part 2 solution
-    0
-}}
+    let plan = plan(&path, &options, &config, &template, existing_workspace)?;
 
-#[cfg(test)]
-mod tests {{
-    use super::*;
+    if options.dry_run {
+        if plan.has_changes() {
+            plan.print(true);
+        } else {
+            println!("Nothing to do, {:?} is up to date", path);
+        }
+        return Ok(());
+    }
 
-    #[test]
-    fn test_part1() {{
-        let input = "";
-        assert_eq!(part1(input), 0);
-    }}
+    if existing_workspace && !options.upgrade {
+        if plan.has_changes() {
+            plan.print(false);
+            bail!(
+                "{:?} already contains a workspace; rerun with --upgrade to apply the changes \
+                 above or with --dry-run to see a diff",
+                path
+            );
+        }
+        println!("{:?} is already up to date", path);
+        return Ok(());
+    }
 
-    #[test]
-    fn test_part2() {{
-        let input = "";
-        assert_eq!(part2(input), 0);
-    }}
-}}
-"#,
-        day
-    );
-    fs::write(day_path.join("src").join("lib.rs"), day_lib)?;
+    if existing_workspace {
+        plan.print(false);
+    }
+    fs::create_dir_all(&path)?;
+    plan.apply(&path)?;
+
+    if options.no_git {
+        // Nothing to do
+    } else if !git::available() {
+        eprintln!("Warning: git is not installed or not on the PATH, skipping repository setup");
+    } else if existing_workspace {
+        if git::is_repo(&path) {
+            println!("Left the changes uncommitted, review and commit them");
+        }
+    } else {
+        init_git_repo(&path).context("The workspace was created, but setting up git failed")?;
+    }
 
+    if existing_workspace {
+        println!(
+            "Successfully upgraded Advent of Code workspace at {:?}",
+            path
+        );
+    } else {
+        println!(
+            "Successfully initialized Advent of Code workspace at {:?}",
+            path
+        );
+    }
+    Ok(())
+}
+
+/// Work out every file the workspace should have and how it differs from disk.
+fn plan(
+    root: &Path,
+    options: &InitOptions,
+    config: &Config,
+    template: &Template,
+    existing_workspace: bool,
+) -> Result<Plan> {
+    let mut files = Vec::new();
+
+    // Workspace manifest, merged into the existing one
+    let mut manifest: DocumentMut = match read_existing(root, "Cargo.toml")? {
+        Some(existing) => existing.parse().context("Failed to parse Cargo.toml")?,
+        None => format!(
+            "[workspace]\n{}\nresolver = \"2\"\n\n[workspace.dependencies]\nanyhow = \"1.0.75\"\n",
+            if options.empty {
+                EMPTY_WORKSPACE_MEMBERS
+            } else {
+                WORKSPACE_MEMBERS
+            }
+        )
+        .parse()?,
+    };
+    let days: Vec<u8> = if existing_workspace {
+        // Restore missing members, new days are added with `aocr new`
+        workspace::members(&manifest)
+            .iter()
+            .filter_map(|m| m.strip_prefix("day")?.parse().ok())
+            .collect()
+    } else if options.empty {
+        Vec::new()
+    } else {
+        (1..=25).collect()
+    };
+    workspace::insert_member(&mut manifest, "runner")?;
+    workspace::insert_workspace_dependency(&mut manifest, "anyhow", toml_edit::value("1.0.75"))?;
+    if config.common {
+        workspace::insert_member(&mut manifest, COMMON_CRATE)?;
+        workspace::insert_workspace_dependency(
+            &mut manifest,
+            COMMON_CRATE,
+            workspace::path_dependency(COMMON_CRATE),
+        )?;
+    }
+    files.push(PlannedFile {
+        path: PathBuf::from("Cargo.toml"),
+        contents: manifest.to_string(),
+        overwrite: true,
+    });
+
+    // Workspace config, keeping any hand-written settings
+    let config_contents = match read_existing(root, CONFIG_FILE)? {
+        Some(existing) => {
+            let mut document: DocumentMut = existing
+                .parse()
+                .with_context(|| format!("Failed to parse {}", CONFIG_FILE))?;
+            if config.common {
+                document["common"] = toml_edit::value(true);
+            }
+            document.to_string()
+        }
+        None => toml::to_string(config)?,
+    };
+    files.push(PlannedFile {
+        path: PathBuf::from(CONFIG_FILE),
+        contents: config_contents,
+        overwrite: true,
+    });
+
+    // Ignore rules, appending whatever is missing
+    let gitignore = match read_existing(root, ".gitignore")? {
+        Some(existing) => {
+            let missing: Vec<&str> = GITIGNORE
+                .lines()
+                .filter(|line| !line.is_empty() && !line.starts_with('#'))
+                .filter(|line| !existing.lines().any(|l| l.trim() == *line))
+                .collect();
+            if missing.is_empty() {
+                existing
+            } else {
+                let separator = if existing.ends_with('\n') {
+                    "\n"
+                } else {
+                    "\n\n"
+                };
+                format!(
+                    "{}{}# Added by aocr\n{}\n",
+                    existing,
+                    separator,
+                    missing.join("\n")
+                )
+            }
+        }
+        None => GITIGNORE.to_string(),
+    };
+    files.push(PlannedFile {
+        path: PathBuf::from(".gitignore"),
+        contents: gitignore,
+        overwrite: true,
+    });
+
+    // The runner is regenerated on every run, so it only needs to exist
+    files.push(PlannedFile {
+        path: PathBuf::from("runner/Cargo.toml"),
+        contents: RUNNER_TOML.to_string(),
+        overwrite: false,
+    });
+    files.push(PlannedFile {
+        path: PathBuf::from("runner/src/main.rs"),
+        contents: RUNNER_MAIN.to_string(),
+        overwrite: false,
+    });
+
+    if config.common {
+        for (relative, contents) in common::render(config.year) {
+            files.push(PlannedFile {
+                path: Path::new(COMMON_CRATE).join(relative),
+                contents,
+                overwrite: false,
+            });
+        }
+    }
+
+    for day in days {
+        files.extend(plan_day_crate(root, day, template, config)?);
+    }
+
+    let files = files
+        .into_iter()
+        .map(|file| {
+            let change = match read_existing(root, &file.path)? {
+                None => Change::Create,
+                Some(old) if old == file.contents => Change::Unchanged,
+                Some(old) if file.overwrite => Change::Update { old },
+                Some(_) => Change::Keep,
+            };
+            Ok((file, change))
+        })
+        .collect::<Result<_>>()?;
+
+    // Inputs saved by older versions of `aocr run`
+    let mut moves = Vec::new();
+    for day in 1..=25 {
+        for part in 1..=2 {
+            let (from, to) = (input::legacy_path(day, part), input::path(day, part));
+            if root.join(&from).exists() && !root.join(&to).exists() {
+                moves.push((from, to));
+            }
+        }
+    }
+
+    Ok(Plan { files, moves })
+}
+
+fn read_existing(root: &Path, relative: impl AsRef<Path>) -> Result<Option<String>> {
+    let path = root.join(relative);
+    match fs::read_to_string(&path) {
+        Ok(contents) => Ok(Some(contents)),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(e).with_context(|| format!("Failed to read {:?}", path)),
+    }
+}
+
+/// The files of a day crate. Existing solutions are kept, only the manifest is merged.
+fn plan_day_crate(
+    root: &Path,
+    day: u8,
+    template: &Template,
+    config: &Config,
+) -> Result<Vec<PlannedFile>> {
+    let vars = template::Variables {
+        day,
+        year: config.year,
+    };
+    let day_path = PathBuf::from(vars.crate_name());
+
+    let mut files = Vec::new();
+    for (relative, rendered) in template.render(&vars)? {
+        let path = day_path.join(&relative);
+        let is_manifest = relative == Path::new("Cargo.toml");
+        let (mut contents, overwrite) = match read_existing(root, &path)? {
+            Some(existing) if is_manifest => (existing, true),
+            _ => (rendered, false),
+        };
+        if is_manifest && config.common {
+            let mut manifest: DocumentMut = contents
+                .parse()
+                .with_context(|| format!("Failed to parse {:?}", path))?;
+            workspace::insert_inherited_dependency(&mut manifest, COMMON_CRATE)?;
+            contents = manifest.to_string();
+        }
+        files.push(PlannedFile {
+            path,
+            contents,
+            overwrite,
+        });
+    }
+    Ok(files)
+}
+
+/// Commit the new workspace, creating a repository unless it is already inside one.
+fn init_git_repo(path: &Path) -> Result<()> {
+    if git::is_repo(path) {
+        println!(
+            "{:?} is already inside a git repository, skipping `git init`",
+            path
+        );
+    } else {
+        git::run(path, &["init"]).context("Failed to initialize git repository")?;
+    }
+
+    // Limit staging and committing to the workspace in case the repository is shared
+    git::run(path, &["add", "--", "."]).context("Failed to stage files")?;
+    git::run(
+        path,
+        &[
+            "commit",
+            "-m",
+            "Initial commit: Advent of Code workspace",
+            "--",
+            ".",
+        ],
+    )
+    .context("Failed to create initial commit")?;
+
+    Ok(())
+}
+
+/// Create the crate for `day` in the workspace at `workspace_path`, keeping existing files.
+pub fn create_day_crate(
+    workspace_path: &Path,
+    day: u8,
+    template: &Template,
+    config: &Config,
+) -> Result<()> {
+    for file in plan_day_crate(workspace_path, day, template, config)? {
+        let path = workspace_path.join(&file.path);
+        if path.exists() && !file.overwrite {
+            continue;
+        }
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, file.contents)?;
+    }
     Ok(())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::process::Output;
+    use std::process::{Command, Output};
     use tempfile::TempDir;
 
     fn git_command(dir: &Path, args: &[&str]) -> Result<Output> {
@@ -190,11 +519,12 @@ mod tests {
         let temp_dir = TempDir::new()?;
 
         // Run init command
-        execute(temp_dir.path().to_path_buf())?;
+        execute(temp_dir.path().to_path_buf(), InitOptions::default())?;
 
         // Verify workspace structure
         assert!(temp_dir.path().join("Cargo.toml").exists());
         assert!(temp_dir.path().join(".gitignore").exists());
+        assert!(temp_dir.path().join("aocr.toml").exists());
         assert!(temp_dir.path().join("runner").exists());
         assert!(temp_dir.path().join("runner/src").exists());
         assert!(temp_dir.path().join("runner/src/main.rs").exists());
@@ -262,4 +592,111 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_init_keeps_solutions() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        execute(temp_dir.path().to_path_buf(), InitOptions::default())?;
+
+        let lib_path = temp_dir.path().join("day01/src/lib.rs");
+        let solution =
+            "pub fn part1(_: &str) -> usize { 42 }\npub fn part2(_: &str) -> usize { 0 }\n";
+        fs::write(&lib_path, solution)?;
+
+        // Nothing besides solutions changed, so a second init is a no-op
+        execute(temp_dir.path().to_path_buf(), InitOptions::default())?;
+        execute(
+            temp_dir.path().to_path_buf(),
+            InitOptions {
+                upgrade: true,
+                ..Default::default()
+            },
+        )?;
+        assert_eq!(fs::read_to_string(&lib_path)?, solution);
+        Ok(())
+    }
+
+    #[test]
+    fn test_upgrade_older_workspace() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let root = temp_dir.path();
+        execute(root.to_path_buf(), InitOptions::default())?;
+
+        // Recreate the layout of an older version
+        fs::remove_file(root.join(CONFIG_FILE))?;
+        fs::remove_dir_all(root.join("day05"))?;
+        fs::write(root.join("day01/src/lib.rs"), "// my solution\n")?;
+        fs::create_dir_all(root.join("inputs/day02"))?;
+        fs::write(root.join("inputs/day02/part1.txt"), "1 2 3\n")?;
+
+        // Plain init refuses to touch an existing workspace, dry runs change nothing
+        assert!(execute(root.to_path_buf(), InitOptions::default()).is_err());
+        execute(
+            root.to_path_buf(),
+            InitOptions {
+                dry_run: true,
+                upgrade: true,
+                ..Default::default()
+            },
+        )?;
+        assert!(!root.join(CONFIG_FILE).exists());
+
+        execute(
+            root.to_path_buf(),
+            InitOptions {
+                upgrade: true,
+                common: true,
+                ..Default::default()
+            },
+        )?;
+        assert!(root.join(CONFIG_FILE).exists());
+        assert!(Config::load(root)?.common);
+        assert!(root.join("day05/src/lib.rs").exists());
+        assert!(root.join("common/src/lib.rs").exists());
+        assert_eq!(
+            fs::read_to_string(root.join("day01/src/lib.rs"))?,
+            "// my solution\n"
+        );
+        assert_eq!(fs::read_to_string(root.join(input::path(2, 1)))?, "1 2 3\n");
+        assert!(!root.join("inputs/day02/part1.txt").exists());
+        assert!(fs::read_to_string(root.join("day01/Cargo.toml"))?.contains("common.workspace"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_init_inside_existing_repo() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        git::run(temp_dir.path(), &["init"])?;
+        fs::write(
+            temp_dir.path().join("unrelated.txt"),
+            "not part of the workspace\n",
+        )?;
+        git::run(temp_dir.path(), &["add", "unrelated.txt"])?;
+
+        let workspace = temp_dir.path().join("aoc24");
+        execute(workspace.clone(), InitOptions::default())?;
+
+        // No nested repository, and only the workspace was committed
+        assert!(!workspace.join(".git").exists());
+        let committed = git::run(temp_dir.path(), &["show", "--name-only", "--format="])?;
+        assert!(committed.contains("aoc24/Cargo.toml"));
+        assert!(!committed.contains("unrelated.txt"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_init_without_git() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        execute(
+            temp_dir.path().to_path_buf(),
+            InitOptions {
+                no_git: true,
+                empty: true,
+                ..Default::default()
+            },
+        )?;
+        assert!(temp_dir.path().join("Cargo.toml").exists());
+        assert!(!temp_dir.path().join(".git").exists());
+        Ok(())
+    }
 }
@@ -0,0 +1,17 @@
+// src/commands/input.rs
+use crate::input::check;
+use anyhow::{bail, Result};
+use std::path::Path;
+
+pub fn execute_preserve(day: u8, off: bool) -> Result<()> {
+    if !(1..=25).contains(&day) {
+        bail!("Day must be between 1 and 25, got {}", day);
+    }
+    check::set_preserve(Path::new("."), day, !off)?;
+    if off {
+        println!("Inputs of day {} are normalized again", day);
+    } else {
+        println!("Inputs of day {} are kept byte for byte", day);
+    }
+    Ok(())
+}
@@ -0,0 +1,38 @@
+// src/commands/leaderboard.rs
+use crate::aoc::{self, leaderboard};
+use crate::config::Config;
+use anyhow::{bail, Result};
+use std::path::{Path, PathBuf};
+
+pub fn execute(id: Option<String>, file: Option<PathBuf>, day: Option<u8>) -> Result<()> {
+    if day.is_some_and(|day| !(1..=25).contains(&day)) {
+        bail!("Day must be between 1 and 25, got {}", day.unwrap());
+    }
+    let root = Path::new(".");
+    let config = Config::load(root)?;
+
+    let leaderboard = match file {
+        Some(file) => leaderboard::load_file(&file)?,
+        None => {
+            let Some(id) = id.or(config.aoc.leaderboard.clone()) else {
+                bail!("No leaderboard id given and none set as `aoc.leaderboard` in aocr.toml");
+            };
+            let client = aoc::Client::from_config(root, &config);
+            let (leaderboard, source) = leaderboard::load(root, &client, config.year, &id)?;
+            if let leaderboard::Source::Cached(age) = source {
+                println!(
+                    "Cached {} ago, refreshed at most every {} minutes",
+                    aoc::format_elapsed(age.as_secs()),
+                    leaderboard::REFRESH_INTERVAL.as_secs() / 60
+                );
+            }
+            leaderboard
+        }
+    };
+
+    println!("Advent of Code {}", leaderboard.event);
+    for line in leaderboard.render(day) {
+        println!("{}", line);
+    }
+    Ok(())
+}
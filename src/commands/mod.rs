@@ -1,3 +1,13 @@
+pub mod common;
+pub mod compare;
+pub mod crosscheck;
+pub mod examples;
 pub mod init;
+pub mod input;
+pub mod leaderboard;
+pub mod new;
+pub mod puzzle;
 pub mod run;
+pub mod stats;
+pub mod submit;
 pub mod watch;
@@ -0,0 +1,87 @@
+// src/commands/new.rs
+use super::init::create_day_crate;
+use crate::config::Config;
+use crate::git;
+use crate::template::Template;
+use crate::workspace;
+use anyhow::{bail, Result};
+use std::path::Path;
+
+pub fn execute(day: u8) -> Result<()> {
+    create(Path::new("."), day)?;
+    println!("Created day{:02}", day);
+    Ok(())
+}
+
+/// Create the crate for `day` in the workspace at `root` and register it as a member.
+pub fn create(root: &Path, day: u8) -> Result<()> {
+    if !(1..=25).contains(&day) {
+        bail!("Day must be between 1 and 25, got {}", day);
+    }
+    if !root.join("Cargo.toml").exists() {
+        bail!("No Cargo.toml found; run `aocr new` from the root of an aocr workspace");
+    }
+
+    let crate_name = format!("day{:02}", day);
+    if root.join(&crate_name).exists() {
+        bail!("{} already exists", crate_name);
+    }
+
+    let config = Config::load(root)?;
+    let template = Template::resolve(&config.template)?;
+    create_day_crate(root, day, &template, &config)?;
+    workspace::add_member(root, &crate_name)?;
+
+    if git::is_repo(root) {
+        if let Err(e) = git::commit_paths(
+            root,
+            &[&crate_name, "Cargo.toml"],
+            &format!("Add day {}", day),
+        ) {
+            eprintln!(
+                "Warning: created {} but could not commit it: {}",
+                crate_name, e
+            );
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::init::{self, InitOptions};
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_new_day_in_empty_workspace() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        init::execute(
+            temp_dir.path().to_path_buf(),
+            InitOptions {
+                empty: true,
+                ..Default::default()
+            },
+        )?;
+        assert!(!temp_dir.path().join("day01").exists());
+
+        create(temp_dir.path(), 7)?;
+
+        let lib = fs::read_to_string(temp_dir.path().join("day07/src/lib.rs"))?;
+        assert!(lib.contains("Day 7"));
+        let manifest = fs::read_to_string(temp_dir.path().join("Cargo.toml"))?;
+        assert!(manifest.contains("\"day07\""));
+
+        // The new day is committed on its own
+        let status = git::run(temp_dir.path(), &["status", "--porcelain"])?;
+        assert!(status.is_empty(), "uncommitted changes: {}", status);
+        let log = git::run(temp_dir.path(), &["log", "--oneline"])?;
+        assert!(log.contains("Add day 7"));
+
+        assert!(create(temp_dir.path(), 7).is_err());
+        assert!(create(temp_dir.path(), 26).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,17 @@
+// src/commands/puzzle.rs
+use crate::aoc::{self, puzzle};
+use crate::config::Config;
+use anyhow::{bail, Result};
+use std::path::Path;
+
+pub fn execute(day: u8, refresh: bool) -> Result<()> {
+    if !(1..=25).contains(&day) {
+        bail!("Day must be between 1 and 25, got {}", day);
+    }
+    let root = Path::new(".");
+    let config = Config::load(root)?;
+    let client = aoc::Client::from_config(root, &config);
+    let puzzle = puzzle::load(root, &client, config.year, day, refresh)?;
+    print!("{}", puzzle);
+    Ok(())
+}
@@ -1,38 +1,84 @@
 // src/commands/run.rs
-use crate::runner::Runner;
+use crate::config::Config;
+use crate::history;
+use crate::input::{self, check};
+use crate::runner::{Failure, Runner};
+use crate::state;
 use anyhow::Result;
 use dialoguer::Editor;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
-pub fn execute(day: u8, part: u8, input_path: Option<PathBuf>) -> Result<()> {
-    let runner = Runner::default();
+pub fn execute(
+    day: u8,
+    part: u8,
+    input_path: Option<PathBuf>,
+    input_name: Option<String>,
+) -> Result<()> {
+    let root = Path::new(".");
+    let runner = Runner::default().with_backtrace(Config::load(root)?.run.backtrace);
+    if let Some(name) = &input_name {
+        input::validate_name(name)?;
+    }
+    let name = input_name.as_deref().unwrap_or(input::DEFAULT_NAME);
+    let input_file = input::named_path(day, part, name);
 
     // Get input either from file or editor
     let input = if let Some(path) = input_path {
-        fs::read_to_string(path)?
+        let input = fs::read_to_string(path)?;
+        if input_name.is_some() {
+            // Keep a named input for later runs
+            fs::create_dir_all(input::INPUTS_DIR)?;
+            fs::write(&input_file, check::prepare(root, day, name, &input)?.input)?;
+        }
+        input
     } else {
         // Try to load from saved inputs first
-        let input_file = format!("inputs/day{:02}/part{}.txt", day, part);
         if let Ok(content) = fs::read_to_string(&input_file) {
             content
         } else {
             // Open editor for input
             let input = Editor::new()
-                .edit("# Enter your input here\n")?
+                .edit(&format!("{}\n", check::EDITOR_PROMPT))?
                 .ok_or_else(|| anyhow::anyhow!("Input was not provided"))?;
 
             // Save input for future use
-            fs::create_dir_all(format!("inputs/day{:02}", day))?;
+            let input = check::prepare(root, day, name, &input)?.input;
+            fs::create_dir_all(input::INPUTS_DIR)?;
             fs::write(&input_file, &input)?;
 
             input
         }
     };
 
+    let checked = check::prepare(root, day, name, &input)?;
+    for warning in &checked.warnings {
+        eprintln!("Warning: {}", warning);
+    }
+
     // Run the solution
-    let result = runner.run_day(day, part, &input)?;
-    println!("Day {} Part {}: {}", day, part, result);
+    let (solution, output) = match runner.run_day(day, part, &checked.input) {
+        Ok(run) => run,
+        Err(e) => {
+            // Show where it panicked and what it printed before the error itself
+            if let Some(failure) = e.downcast_ref::<Failure>() {
+                eprint!("{}", failure.render(root, false));
+            }
+            return Err(e);
+        }
+    };
+    print!("{}", output.render());
+    let on = if name == input::DEFAULT_NAME {
+        String::new()
+    } else {
+        format!(" on {}", name)
+    };
+    println!(
+        "Day {} Part {}{}: {} ({:.1?})",
+        day, part, on, solution.answer, solution.duration
+    );
+    state::record_run(root, day, part, name, solution.answer, solution.duration)?;
+    history::record(root, day, part, name, &solution)?;
 
     Ok(())
 }
@@ -0,0 +1,37 @@
+// src/commands/stats.rs
+use crate::aoc::format_elapsed;
+use crate::config::Config;
+use crate::state::DayState;
+use anyhow::Result;
+use std::path::Path;
+
+pub fn execute() -> Result<()> {
+    let root = Path::new(".");
+    let config = Config::load(root)?;
+
+    println!(
+        "{:>3}  {:>12} {:>6}  {:>12} {:>6}",
+        "Day", "Part 1", "Wrong", "Part 2", "Wrong"
+    );
+    for day in 1..=25 {
+        let state = DayState::load(root, day)?;
+        if state == DayState::default() {
+            continue;
+        }
+        let mut line = format!("{:>3}", day);
+        for part in 1..=2 {
+            let part_state = state.part(part);
+            let time = part_state
+                .time_to_star(config.year, day)
+                .map_or_else(|| "-".to_string(), format_elapsed);
+            line.push_str(&format!(
+                "  {:>12} {:>6}",
+                time,
+                part_state.wrong_answers.len()
+            ));
+        }
+        println!("{}", line);
+    }
+    println!("\nTimes are measured from the puzzle unlocking to the star being earned.");
+    Ok(())
+}
@@ -0,0 +1,37 @@
+// src/commands/submit.rs
+use crate::aoc::{self, submit::Verdict};
+use crate::config::Config;
+use crate::git;
+use crate::state::DayState;
+use anyhow::{bail, Result};
+use std::path::Path;
+
+pub fn execute(day: u8, part: u8, answer: Option<String>) -> Result<()> {
+    if !(1..=25).contains(&day) || !(1..=2).contains(&part) {
+        bail!("Expected a day between 1 and 25 and a part of 1 or 2");
+    }
+    let root = Path::new(".");
+    let config = Config::load(root)?;
+    let state = DayState::load(root, day)?;
+
+    let Some(answer) = answer.or_else(|| state.part(part).last_answer.clone()) else {
+        bail!(
+            "No answer given and day {} part {} has not been run yet",
+            day,
+            part
+        );
+    };
+
+    println!("Submitting {} for day {} part {}", answer, day, part);
+    let client = aoc::Client::from_config(root, &config);
+    let verdict = aoc::submit::submit(root, &client, config.year, day, part, &answer)?;
+    println!("{}", verdict);
+
+    if verdict == Verdict::Correct {
+        let runtime = DayState::load(root, day)?.part(part).last_runtime();
+        if let Some(message) = git::commit_solved(root, &config.git, day, part, runtime)? {
+            println!("Committed: {}", message);
+        }
+    }
+    Ok(())
+}
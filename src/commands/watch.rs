@@ -1,9 +1,17 @@
 // src/commands/watch.rs
-use crate::runner::Runner;
-use crate::tui::app::{App, Direction};
+use crate::history;
+use crate::input;
+use crate::input::check::{self, Checked};
+use crate::runner::{Failure, Runner, BENCH_RUNS};
+use crate::state;
+use crate::tui::app::{App, Direction, SidePane};
+use crate::tui::external_editor;
+use crate::tui::keymap::Action;
+use crate::tui::palette::{Command, Palette};
+use crate::tui::search::{self, Search};
 use anyhow::{Context, Result};
 use arboard::Clipboard;
-use crossterm::event::{self, Event, KeyCode, KeyEventKind, KeyModifiers};
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
 use notify::{RecursiveMode, Watcher};
 use ratatui::{
     backend::{Backend, CrosstermBackend},
@@ -13,7 +21,8 @@ use ratatui::{
     },
     Terminal,
 };
-use std::path::PathBuf;
+use std::fs;
+use std::path::{Path, PathBuf};
 use std::sync::mpsc;
 use std::time::Duration;
 
@@ -30,28 +39,71 @@ fn run_tests(app: &mut App, runner: &Runner) {
         "Testing day {} part {}...\n",
         app.watched_day, app.watched_part
     );
-    let result = runner.test_day(app.watched_day);
-    app.cargo_output.push_str(&result);
+    let report = runner.test_day(app.watched_day);
+    app.cargo_output.push_str(&report.output);
+    if let Err(e) = state::record_tests(Path::new("."), app.watched_day, report.passed) {
+        app.cargo_output
+            .push_str(&format!("\nFailed to save state: {:#}", e));
+    }
+    app.reload_state(app.watched_day);
 }
 
-fn setup_watcher() -> Result<(notify::RecommendedWatcher, mpsc::Receiver<notify::Event>)> {
-    let (tx, rx) = mpsc::channel();
-    let mut watcher = notify::recommended_watcher(move |res| {
-        if let Ok(event) = res {
-            let _ = tx.send(event);
-        }
-    })?;
+type FsEvents = mpsc::Receiver<notify::Event>;
+
+/// Drop the file system events received so far.
+fn drain(events: &FsEvents) {
+    while events.try_recv().is_ok() {}
+}
+
+/// Watches the `src` of every day crate, including the days `aocr new` creates while the
+/// TUI runs: the workspace root is watched too, without recursing, to notice them.
+struct DayWatcher {
+    watcher: notify::RecommendedWatcher,
+    root: PathBuf,
+    days: Vec<PathBuf>,
+}
+
+impl DayWatcher {
+    fn new() -> Result<(Self, FsEvents)> {
+        let (tx, rx) = mpsc::channel();
+        let mut watcher = notify::recommended_watcher(move |res| {
+            if let Ok(event) = res {
+                let _ = tx.send(event);
+            }
+        })?;
+        let root = std::env::current_dir()?;
+        watcher.watch(&root, RecursiveMode::NonRecursive)?;
+
+        let mut day_watcher = Self {
+            watcher,
+            root,
+            days: Vec::new(),
+        };
+        day_watcher.watch_new_days()?;
+        Ok((day_watcher, rx))
+    }
 
-    // Watch each day's src directory specifically
-    for day in 1..=25 {
-        let day_path = PathBuf::from(format!("day{:02}/src", day));
-        if day_path.exists() {
-            watcher.watch(&day_path, RecursiveMode::Recursive)?;
+    /// Start watching the day `src` directories that appeared since the last call.
+    fn watch_new_days(&mut self) -> Result<()> {
+        for day in 1..=25 {
+            let day_path = self.root.join(format!("day{:02}/src", day));
+            if day_path.exists() && !self.days.contains(&day_path) {
+                self.watcher.watch(&day_path, RecursiveMode::Recursive)?;
+                self.days.push(day_path);
+            }
         }
+        Ok(())
     }
 
-    Ok((watcher, rx))
+    /// Whether the event is about a day's sources rather than the workspace root.
+    fn is_day_event(&self, event: &notify::Event) -> bool {
+        event
+            .paths
+            .iter()
+            .any(|path| self.days.iter().any(|day| path.starts_with(day)))
+    }
 }
+
 pub fn init_panic_hook() {
     let original_hook = std::panic::take_hook();
     std::panic::set_hook(Box::new(move |panic_info| {
@@ -61,17 +113,374 @@ pub fn init_panic_hook() {
     }));
 }
 pub fn init_tui() -> std::io::Result<Terminal<impl Backend>> {
-    enable_raw_mode()?;
-    execute!(std::io::stdout(), EnterAlternateScreen)?;
+    enter_tui()?;
     Terminal::new(CrosstermBackend::new(std::io::stdout()))
 }
 
+fn enter_tui() -> std::io::Result<()> {
+    enable_raw_mode()?;
+    execute!(std::io::stdout(), EnterAlternateScreen)
+}
+
 pub fn restore_tui() -> std::io::Result<()> {
     execute!(std::io::stdout(), LeaveAlternateScreen)?;
     disable_raw_mode()?;
     Ok(())
 }
 
+/// Route a key press to the help overlay, the palette or the keymap.
+///
+/// Returns `false` when the TUI should quit.
+fn handle_key<B: Backend>(
+    key: KeyEvent,
+    app: &mut App,
+    runner: &Runner,
+    clipboard: &mut Clipboard,
+    terminal: &mut Terminal<B>,
+    events: &FsEvents,
+) -> Result<bool> {
+    if app.show_help {
+        // Any key closes the help
+        app.show_help = false;
+        return Ok(true);
+    }
+
+    if let Some(palette) = app.palette.as_mut() {
+        let count = palette.matches().len();
+        match (key.code, key.modifiers) {
+            (KeyCode::Esc, _) => app.palette = None,
+            (KeyCode::Up, _) | (KeyCode::Char('p'), KeyModifiers::CONTROL) => {
+                palette.select_previous()
+            }
+            (KeyCode::Down, _) | (KeyCode::Char('n'), KeyModifiers::CONTROL) => {
+                palette.select_next(count)
+            }
+            (KeyCode::Backspace, _) => palette.pop(),
+            (KeyCode::Enter, _) => {
+                let command = palette.matches().get(palette.selected).copied();
+                app.palette = None;
+                match command {
+                    Some(Command::Action(action)) => {
+                        return perform(action, app, runner, clipboard, terminal, events)
+                    }
+                    Some(Command::GoToDay(day)) => app.select_day(day),
+                    None => {}
+                }
+            }
+            (KeyCode::Char(c), KeyModifiers::NONE | KeyModifiers::SHIFT) => palette.push(c),
+            _ => {}
+        }
+        return Ok(true);
+    }
+
+    if let Some(search) = app.search.as_mut() {
+        match (key.code, key.modifiers) {
+            (KeyCode::Esc, _) => app.search = None,
+            (KeyCode::Enter | KeyCode::Down, _) | (KeyCode::Char('n'), KeyModifiers::CONTROL) => {
+                app.find_match(true, true)
+            }
+            (KeyCode::Up, _) | (KeyCode::Char('p'), KeyModifiers::CONTROL) => {
+                app.find_match(false, true)
+            }
+            (KeyCode::Backspace, _) => {
+                search.query.pop();
+                app.find_match(true, false);
+            }
+            (KeyCode::Char(c), KeyModifiers::NONE | KeyModifiers::SHIFT) => {
+                search.query.push(c);
+                app.find_match(true, false);
+            }
+            _ => {}
+        }
+        return Ok(true);
+    }
+
+    match app.keymap.lookup(app.mode(), &key) {
+        Some(action) => perform(action, app, runner, clipboard, terminal, events),
+        None => {
+            // Unbound characters are typed into the input
+            if let (true, KeyCode::Char(c)) = (app.input_mode, key.code) {
+                if matches!(key.modifiers, KeyModifiers::NONE | KeyModifiers::SHIFT) {
+                    app.editor.insert_char(c);
+                }
+            }
+            Ok(true)
+        }
+    }
+}
+
+/// Carry out an action, returning `false` when the TUI should quit.
+fn perform<B: Backend>(
+    action: Action,
+    app: &mut App,
+    runner: &Runner,
+    clipboard: &mut Clipboard,
+    terminal: &mut Terminal<B>,
+    events: &FsEvents,
+) -> Result<bool> {
+    match action {
+        Action::Quit => return Ok(false),
+        Action::Help => app.show_help = true,
+        Action::Palette => app.palette = Some(Palette::default()),
+        Action::Left => app.move_cursor(Direction::Left),
+        Action::Right => app.move_cursor(Direction::Right),
+        Action::Up => app.move_cursor(Direction::Up),
+        Action::Down => app.move_cursor(Direction::Down),
+        Action::PageUp if app.input_mode => app.editor.move_lines(-10, false),
+        Action::PageDown if app.input_mode => app.editor.move_lines(10, false),
+        Action::PageUp => app.page_up(),
+        Action::PageDown => app.page_down(),
+        Action::ScrollUp if app.input_mode => app.editor.move_lines(-1, false),
+        Action::ScrollDown if app.input_mode => app.editor.move_lines(1, false),
+        Action::ScrollUp => app.scroll_up(),
+        Action::ScrollDown => app.scroll_down(),
+        Action::ToggleFollow => app.follow = !app.follow,
+        Action::Search => app.search = Some(Search::default()),
+        Action::Watch => {
+            app.watch(app.selected_day, app.selected_part);
+            run_check(app, runner);
+        }
+        Action::Test => run_tests(app, runner),
+        Action::Run => run_selected(app, runner),
+        Action::Bench => bench_selected(app, runner),
+        Action::EditInput => {
+            app.input_mode = true;
+            app.load_input();
+            app.editor.move_document_end();
+        }
+        Action::OpenEditor => open_editor(app, runner, terminal, events)?,
+        Action::ToggleSolved => match app.toggle_completion() {
+            Ok(Some(message)) => app.cargo_output = format!("Committed: {}", message),
+            Ok(None) => {}
+            Err(e) => app.cargo_output = format!("Error: {:#}", e),
+        },
+        Action::Submit => {
+            app.cargo_output = match app.submit() {
+                Ok(message) => message,
+                Err(e) => format!("Error: {:#}", e),
+            };
+        }
+        Action::TogglePuzzle => {
+            if let Err(e) = app.toggle_puzzle() {
+                app.cargo_output = format!("Error: {:#}", e);
+            }
+        }
+        Action::FetchPuzzle => {
+            app.side_pane = Some(SidePane::Puzzle);
+            app.side_scroll = 0;
+            app.cargo_output = format!("Fetching the puzzle for day {}...", app.selected_day);
+            terminal.draw(|f| crate::tui::ui::draw(f, app))?;
+            match app.load_puzzle(true) {
+                Ok(()) => app.cargo_output.clear(),
+                Err(e) => app.cargo_output = format!("Error: {:#}", e),
+            }
+        }
+        Action::ToggleLeaderboard => {
+            if let Err(e) = app.toggle_leaderboard() {
+                app.cargo_output = format!("Error: {:#}", e);
+            }
+        }
+        Action::ToggleInspector => app.toggle_inspector(),
+        Action::ToggleHistory => app.toggle_history(),
+        Action::NextInput => {
+            app.next_input();
+            app.cargo_output = format!("Using input {:?}", app.input_name);
+        }
+        Action::SaveExamples => {
+            app.cargo_output = match app.save_examples() {
+                Ok(summary) => summary,
+                Err(e) => format!("Error: {:#}", e),
+            };
+        }
+        Action::ToggleStats => app.show_stats = !app.show_stats,
+        Action::SideScrollUp => app.side_scroll = app.side_scroll.saturating_sub(1),
+        Action::SideScrollDown => app.side_scroll += 1,
+        Action::SaveInput => match app.set_input(app.editor.text()) {
+            Ok(warnings) => {
+                app.input_mode = false;
+                app.cargo_output = warnings
+                    .iter()
+                    .map(|warning| format!("Warning: {}\n", warning))
+                    .collect();
+            }
+            Err(e) => app.cargo_output = format!("Error: {:#}", e),
+        },
+        Action::Paste => {
+            if let Ok(text) = clipboard.get_text() {
+                app.editor.insert_text(&text);
+            }
+        }
+        Action::Copy => {
+            if let Some(text) = app.editor.selected_text() {
+                let _ = clipboard.set_text(text);
+            }
+        }
+        Action::Cut => {
+            if let Some(text) = app.editor.cut() {
+                let _ = clipboard.set_text(text);
+            }
+        }
+        Action::Newline => app.editor.insert_newline(),
+        Action::CursorLeft => app.editor.move_left(false),
+        Action::CursorRight => app.editor.move_right(false),
+        Action::CursorUp => app.editor.move_lines(-1, false),
+        Action::CursorDown => app.editor.move_lines(1, false),
+        Action::SelectLeft => app.editor.move_left(true),
+        Action::SelectRight => app.editor.move_right(true),
+        Action::SelectUp => app.editor.move_lines(-1, true),
+        Action::SelectDown => app.editor.move_lines(1, true),
+        Action::WordLeft => app.editor.move_word_left(false),
+        Action::WordRight => app.editor.move_word_right(false),
+        Action::LineStart => app.editor.move_line_start(),
+        Action::LineEnd => app.editor.move_line_end(),
+        Action::DocumentStart => app.editor.move_document_start(),
+        Action::DocumentEnd => app.editor.move_document_end(),
+        Action::DeleteBack => app.editor.delete_back(),
+        Action::DeleteForward => app.editor.delete_forward(),
+        Action::Undo => app.editor.undo(),
+        Action::Redo => app.editor.redo(),
+        Action::CancelInput => app.input_mode = false,
+    }
+    Ok(true)
+}
+
+/// Suspend the TUI while `$VISUAL`/`$EDITOR` edits the selected day, then check it.
+fn open_editor<B: Backend>(
+    app: &mut App,
+    runner: &Runner,
+    terminal: &mut Terminal<B>,
+    events: &FsEvents,
+) -> Result<()> {
+    let day = app.selected_day;
+    let Some(editor) = external_editor::configured() else {
+        app.cargo_output = String::from("Set $VISUAL or $EDITOR to open the code in an editor.");
+        return Ok(());
+    };
+    let location = external_editor::first_diagnostic(&search::strip_ansi(&app.cargo_output), day)
+        .unwrap_or_else(|| external_editor::Location::lib(day));
+    if !location.path.exists() {
+        app.cargo_output = format!("{} does not exist yet.", location.path.display());
+        return Ok(());
+    }
+
+    restore_tui()?;
+    let status = external_editor::command(&editor, &location).status();
+    enter_tui()?;
+    terminal.clear()?;
+
+    match status {
+        Ok(_) => {
+            // The editor's writes, swap and backup files are covered by this one check
+            drain(events);
+            app.watch(day, app.selected_part);
+            run_check(app, runner);
+        }
+        Err(e) => app.cargo_output = format!("Failed to run {}: {}", editor, e),
+    }
+    Ok(())
+}
+
+/// The selected input, ready to run, with its warnings as output lines. Shows why in
+/// the output pane when there is none.
+fn selected_input(app: &mut App) -> Option<(Checked, String)> {
+    let Ok(input) = fs::read_to_string(app.input_path()) else {
+        app.cargo_output = format!(
+            "No input {:?} provided. Press 'i' to add input.",
+            app.input_name
+        );
+        return None;
+    };
+    let checked = match check::prepare(Path::new("."), app.selected_day, &app.input_name, &input) {
+        Ok(checked) => checked,
+        Err(e) => {
+            app.cargo_output = format!("Error: {:#}", e);
+            return None;
+        }
+    };
+    let warnings: String = checked
+        .warnings
+        .iter()
+        .map(|warning| format!("Warning: {}\n", warning))
+        .collect();
+    Some((checked, warnings))
+}
+
+fn bench_selected(app: &mut App, runner: &Runner) {
+    let Some((checked, warnings)) = selected_input(app) else {
+        return;
+    };
+    app.cargo_output = match runner.bench(
+        app.selected_day,
+        app.selected_part,
+        &checked.input,
+        BENCH_RUNS,
+    ) {
+        Ok((bench, output)) => format!("{}{}Bench: {}", warnings, output.render(), bench.render()),
+        Err(e) => match e.downcast_ref::<Failure>() {
+            Some(failure) => format!(
+                "{}Error: {}\n{}",
+                warnings,
+                e,
+                failure.render(Path::new("."), true)
+            ),
+            None => format!("{}Error: {}", warnings, e),
+        },
+    };
+}
+
+fn run_selected(app: &mut App, runner: &Runner) {
+    let Some((checked, warnings)) = selected_input(app) else {
+        return;
+    };
+    match runner.run_day(app.selected_day, app.selected_part, &checked.input) {
+        Ok((solution, output)) => {
+            let on = if app.input_name == input::DEFAULT_NAME {
+                String::new()
+            } else {
+                format!(" on {}", app.input_name)
+            };
+            app.cargo_output = format!(
+                "{}{}Result{}: {} ({:.1?})",
+                warnings,
+                output.render(),
+                on,
+                solution.answer,
+                solution.duration
+            );
+            if let Err(e) = state::record_run(
+                Path::new("."),
+                app.selected_day,
+                app.selected_part,
+                &app.input_name,
+                solution.answer,
+                solution.duration,
+            ) {
+                app.cargo_output
+                    .push_str(&format!("\nFailed to save state: {:#}", e));
+            }
+            if let Err(e) = history::record(
+                Path::new("."),
+                app.selected_day,
+                app.selected_part,
+                &app.input_name,
+                &solution,
+            ) {
+                app.cargo_output
+                    .push_str(&format!("\nFailed to save history: {:#}", e));
+            }
+            app.reload_state(app.selected_day);
+            app.load_history();
+        }
+        Err(e) => {
+            app.cargo_output = format!("{}Error: {}\n", warnings, e);
+            if let Some(failure) = e.downcast_ref::<Failure>() {
+                app.cargo_output
+                    .push_str(&failure.render(Path::new("."), true));
+            }
+        }
+    }
+}
+
 pub fn execute() -> Result<()> {
     // Set up panic hook for terminal cleanup
     init_panic_hook();
@@ -79,13 +488,13 @@ pub fn execute() -> Result<()> {
 
     // Create app state and runner
     let mut app = App::new();
-    let runner = Runner::default();
+    let runner = Runner::default().with_backtrace(app.config.run.backtrace.clone());
 
     // Setup clipboard
     let mut clipboard = Clipboard::new().context("Failed to initialize clipboard")?;
 
     // Setup file watcher
-    let (_watcher, rx) = setup_watcher()?;
+    let (mut watcher, rx) = DayWatcher::new()?;
 
     let mut last_cursor_toggle = std::time::Instant::now();
     let cursor_blink_interval = Duration::from_millis(500);
@@ -101,122 +510,25 @@ pub fn execute() -> Result<()> {
         // Draw the current state
         terminal.draw(|f| crate::tui::ui::draw(f, &mut app))?;
 
-        // Check for file system events
-        if let Ok(_event) = rx.try_recv() {
-            run_check(&mut app, &runner);
+        // Check for file system events, once for a burst of them. Changes at the
+        // workspace root only matter when they add a day.
+        let events: Vec<notify::Event> = rx.try_iter().collect();
+        if !events.is_empty() {
+            if let Err(e) = watcher.watch_new_days() {
+                app.cargo_output = format!("Failed to watch a new day: {:#}", e);
+            }
+            if events.iter().any(|event| watcher.is_day_event(event)) {
+                run_check(&mut app, &runner);
+            }
         }
 
         // Handle keyboard events
         if event::poll(Duration::from_millis(0))? {
             if let Event::Key(key) = event::read()? {
-                if key.kind == KeyEventKind::Press {
-                    if app.input_mode {
-                        match (key.code, key.modifiers) {
-                            // Save and exit input mode
-                            (KeyCode::Char('s'), KeyModifiers::CONTROL)
-                            | (KeyCode::Enter, KeyModifiers::CONTROL) => {
-                                app.set_input(app.current_input.clone())?;
-                                app.input_mode = false;
-                                app.current_input.clear();
-                                app.cursor_position = 0;
-                            }
-                            // Paste from clipboard
-                            (KeyCode::Char('v'), KeyModifiers::CONTROL) => {
-                                if let Ok(text) = clipboard.get_text() {
-                                    app.insert_text(&text);
-                                }
-                            }
-                            // Regular enter for newline
-                            (KeyCode::Enter, KeyModifiers::NONE) => {
-                                app.insert_newline();
-                            }
-                            // Navigation
-                            (KeyCode::Left, KeyModifiers::NONE) => app.move_cursor_left(),
-                            (KeyCode::Right, KeyModifiers::NONE) => app.move_cursor_right(),
-                            (KeyCode::Home, _) | (KeyCode::Char('a'), KeyModifiers::CONTROL) => {
-                                app.move_cursor_start()
-                            }
-                            (KeyCode::End, _) | (KeyCode::Char('e'), KeyModifiers::CONTROL) => {
-                                app.move_cursor_end()
-                            }
-                            (KeyCode::PageUp, _) => app.page_up(),
-                            (KeyCode::PageDown, _) => app.page_down(),
-                            (KeyCode::Char('u'), KeyModifiers::CONTROL) => app.page_up(),
-                            (KeyCode::Char('d'), KeyModifiers::CONTROL) => app.page_down(),
-                            (KeyCode::Up, KeyModifiers::CONTROL) => app.scroll_up(),
-                            (KeyCode::Down, KeyModifiers::CONTROL) => app.scroll_down(),
-                            // Editing
-                            (KeyCode::Char(c), KeyModifiers::NONE) => app.insert_char(c),
-                            (KeyCode::Backspace, KeyModifiers::NONE) => app.delete_char(),
-                            (KeyCode::Esc, KeyModifiers::NONE) => {
-                                app.input_mode = false;
-                                app.current_input.clear();
-                                app.cursor_position = 0;
-                            }
-                            _ => {}
-                        }
-                    } else {
-                        match (key.code, key.modifiers) {
-                            (KeyCode::Char('q'), KeyModifiers::NONE) => break,
-                            (KeyCode::Char('h'), KeyModifiers::NONE)
-                            | (KeyCode::Left, KeyModifiers::NONE) => {
-                                app.move_cursor(Direction::Left)
-                            }
-                            (KeyCode::Char('l'), KeyModifiers::NONE)
-                            | (KeyCode::Right, KeyModifiers::NONE) => {
-                                app.move_cursor(Direction::Right)
-                            }
-                            (KeyCode::Char('j'), KeyModifiers::NONE)
-                            | (KeyCode::Down, KeyModifiers::NONE) => {
-                                app.move_cursor(Direction::Down)
-                            }
-                            (KeyCode::Char('k'), KeyModifiers::NONE)
-                            | (KeyCode::Up, KeyModifiers::NONE) => app.move_cursor(Direction::Up),
-                            (KeyCode::PageUp, _) => app.page_up(),
-                            (KeyCode::PageDown, _) => app.page_down(),
-                            (KeyCode::Char('u'), KeyModifiers::CONTROL) => app.page_up(),
-                            (KeyCode::Char('d'), KeyModifiers::CONTROL) => app.page_down(),
-                            (KeyCode::Up, KeyModifiers::CONTROL) => app.scroll_up(),
-                            (KeyCode::Down, KeyModifiers::CONTROL) => app.scroll_down(),
-                            (KeyCode::Char('w'), KeyModifiers::NONE)
-                            | (KeyCode::Enter, KeyModifiers::NONE) => {
-                                app.watch(app.selected_day, app.selected_part);
-                                run_check(&mut app, &runner);
-                            }
-                            (KeyCode::Char('t'), KeyModifiers::NONE) => {
-                                run_tests(&mut app, &runner)
-                            }
-                            (KeyCode::Char('c'), KeyModifiers::NONE) => app.toggle_completion(),
-                            (KeyCode::Char('i'), KeyModifiers::NONE) => {
-                                app.input_mode = true;
-                                app.load_input();
-                                app.cursor_position = app.current_input.len();
-                            }
-                            (KeyCode::Char('r'), KeyModifiers::NONE) => {
-                                if let Some(challenge) = app.get_selected_challenge() {
-                                    if let Some(input) = &challenge.input {
-                                        match runner.run_day(
-                                            app.selected_day,
-                                            app.selected_part,
-                                            input,
-                                        ) {
-                                            Ok(result) => {
-                                                app.cargo_output = format!("Result: {}", result);
-                                            }
-                                            Err(e) => {
-                                                app.cargo_output = format!("Error: {}", e);
-                                            }
-                                        }
-                                    } else {
-                                        app.cargo_output = String::from(
-                                            "No input provided. Press 'i' to add input.",
-                                        );
-                                    }
-                                }
-                            }
-                            _ => {}
-                        }
-                    }
+                if key.kind == KeyEventKind::Press
+                    && !handle_key(key, &mut app, &runner, &mut clipboard, &mut terminal, &rx)?
+                {
+                    break;
                 }
             }
         }
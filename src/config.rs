@@ -0,0 +1,116 @@
+// src/config.rs
+use crate::template::DEFAULT_TEMPLATE;
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+/// Name of the workspace configuration file written by `aocr init`.
+pub const CONFIG_FILE: &str = "aocr.toml";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Config {
+    /// Advent of Code event year
+    pub year: u16,
+    /// Built-in template name or path to a template directory
+    pub template: String,
+    /// Whether the workspace has a shared `common` crate
+    pub common: bool,
+    pub git: GitConfig,
+    pub aoc: AocConfig,
+    #[serde(skip_serializing_if = "RunConfig::is_empty")]
+    pub run: RunConfig,
+    #[serde(skip_serializing_if = "KeysConfig::is_empty")]
+    pub keys: KeysConfig,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GitConfig {
+    /// Commit the day's crate and state when a part is solved
+    pub auto_commit: bool,
+    /// Also tag that commit as `dayNN-partN`
+    pub tag: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AocConfig {
+    /// Site to fetch puzzles from
+    pub base_url: String,
+    /// Id of the private leaderboard shown by `aocr leaderboard` and the TUI
+    pub leaderboard: Option<String>,
+}
+
+/// How solutions are run.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RunConfig {
+    /// `RUST_BACKTRACE` for solutions that panic, e.g. `"1"` or `"full"`
+    pub backtrace: Option<String>,
+}
+
+impl RunConfig {
+    pub fn is_empty(&self) -> bool {
+        self.backtrace.is_none()
+    }
+}
+
+/// Key bindings of `aocr watch` replacing the defaults, as action names mapped to
+/// keys such as `"ctrl+s"`. An empty list unbinds the action.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct KeysConfig {
+    pub navigation: BTreeMap<String, Vec<String>>,
+    pub input: BTreeMap<String, Vec<String>>,
+}
+
+impl KeysConfig {
+    pub fn is_empty(&self) -> bool {
+        self.navigation.is_empty() && self.input.is_empty()
+    }
+}
+
+impl Default for AocConfig {
+    fn default() -> Self {
+        Self {
+            base_url: "https://adventofcode.com".to_string(),
+            leaderboard: None,
+        }
+    }
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            year: 2024,
+            template: DEFAULT_TEMPLATE.to_string(),
+            common: false,
+            git: GitConfig::default(),
+            aoc: AocConfig::default(),
+            run: RunConfig::default(),
+            keys: KeysConfig::default(),
+        }
+    }
+}
+
+impl Config {
+    /// Load the config of the workspace at `root`, using defaults if there is none.
+    pub fn load(root: &Path) -> Result<Self> {
+        let path = root.join(CONFIG_FILE);
+        match fs::read_to_string(&path) {
+            Ok(contents) => {
+                toml::from_str(&contents).with_context(|| format!("Failed to parse {:?}", path))
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e).with_context(|| format!("Failed to read {:?}", path)),
+        }
+    }
+
+    pub fn save(&self, root: &Path) -> Result<()> {
+        fs::write(root.join(CONFIG_FILE), toml::to_string(self)?)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,167 @@
+// src/git.rs
+use crate::config::GitConfig;
+use crate::state::DayState;
+use anyhow::{bail, Context, Result};
+use std::path::Path;
+use std::process::Command;
+use std::time::Duration;
+
+/// Whether a `git` executable can be run at all.
+pub fn available() -> bool {
+    Command::new("git")
+        .arg("--version")
+        .output()
+        .is_ok_and(|output| output.status.success())
+}
+
+/// Run a git command in `dir`, failing with git's stderr if it exits unsuccessfully.
+pub fn run(dir: &Path, args: &[&str]) -> Result<String> {
+    let mut command = Command::new("git");
+    command.args(args).current_dir(dir);
+    // A clean test machine has no identity configured, and `git commit` needs one
+    #[cfg(test)]
+    command.envs([
+        ("GIT_AUTHOR_NAME", "test"),
+        ("GIT_AUTHOR_EMAIL", "test@example.com"),
+        ("GIT_COMMITTER_NAME", "test"),
+        ("GIT_COMMITTER_EMAIL", "test@example.com"),
+    ]);
+    let output = match command.output() {
+        Ok(output) => output,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+            bail!("git is not installed or not on the PATH")
+        }
+        Err(e) => return Err(e).with_context(|| format!("Failed to run `git {}`", args.join(" "))),
+    };
+    if !output.status.success() {
+        bail!(
+            "`git {}` failed: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// Whether `dir` is inside a git work tree.
+pub fn is_repo(dir: &Path) -> bool {
+    run(dir, &["rev-parse", "--is-inside-work-tree"]).is_ok_and(|out| out.trim() == "true")
+}
+
+/// Stage and commit only the given paths, returning whether there was anything to commit.
+pub fn commit_paths(dir: &Path, paths: &[&str], message: &str) -> Result<bool> {
+    let mut add = vec!["add", "--"];
+    add.extend(paths);
+    run(dir, &add)?;
+
+    // `--quiet` exits unsuccessfully exactly when something is staged
+    let mut diff = vec!["diff", "--cached", "--quiet", "--"];
+    diff.extend(paths);
+    if run(dir, &diff).is_ok() {
+        return Ok(false);
+    }
+
+    let mut commit = vec!["commit", "-m", message, "--"];
+    commit.extend(paths);
+    run(dir, &commit)?;
+    Ok(true)
+}
+
+/// Whether the tag exists already.
+pub fn has_tag(dir: &Path, tag: &str) -> bool {
+    run(
+        dir,
+        &["rev-parse", "-q", "--verify", &format!("refs/tags/{}", tag)],
+    )
+    .is_ok()
+}
+
+/// Commit the day's crate and state after a part is solved, if enabled in the config.
+/// Solving a part again commits only what changed and keeps the existing tag.
+///
+/// Returns the commit message when a commit was made.
+pub fn commit_solved(
+    root: &Path,
+    config: &GitConfig,
+    day: u8,
+    part: u8,
+    runtime: Option<Duration>,
+) -> Result<Option<String>> {
+    if !config.auto_commit || !is_repo(root) {
+        return Ok(None);
+    }
+
+    let message = match runtime {
+        Some(runtime) => format!("Day {} part {} solved: {:.1?}", day, part, runtime),
+        None => format!("Day {} part {} solved", day, part),
+    };
+    let crate_dir = format!("day{:02}", day);
+    let state_path = DayState::path(day).to_string_lossy().into_owned();
+    let mut paths = vec![state_path.as_str()];
+    if root.join(&crate_dir).exists() {
+        paths.push(&crate_dir);
+    }
+    let committed = commit_paths(root, &paths, &message)?;
+
+    let tag = format!("day{:02}-part{}", day, part);
+    if config.tag && !has_tag(root, &tag) {
+        run(root, &["tag", "-a", &tag, "-m", &message])?;
+    }
+    Ok(committed.then_some(message))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_failed_command_reports_stderr() -> Result<()> {
+        let dir = TempDir::new()?;
+        assert!(!is_repo(dir.path()));
+
+        let err = run(dir.path(), &["log"]).unwrap_err().to_string();
+        assert!(err.contains("`git log` failed"), "{}", err);
+        assert!(err.contains("not a git repository"), "{}", err);
+        Ok(())
+    }
+
+    #[test]
+    fn test_commit_solved() -> Result<()> {
+        let dir = TempDir::new()?;
+        let root = dir.path();
+        run(root, &["init"])?;
+        std::fs::create_dir_all(root.join("day07/src"))?;
+        std::fs::write(root.join("day07/src/lib.rs"), "// solved\n")?;
+        std::fs::write(root.join("notes.txt"), "not committed\n")?;
+        DayState::default().save(root, 7)?;
+
+        let disabled = GitConfig::default();
+        assert_eq!(commit_solved(root, &disabled, 7, 2, None)?, None);
+
+        let config = GitConfig {
+            auto_commit: true,
+            tag: true,
+        };
+        let message = commit_solved(root, &config, 7, 2, Some(Duration::from_micros(2300)))?;
+        assert_eq!(message.as_deref(), Some("Day 7 part 2 solved: 2.3ms"));
+
+        let committed = run(root, &["show", "--name-only", "--format=%s"])?;
+        assert!(committed.contains("Day 7 part 2 solved: 2.3ms"));
+        assert!(committed.contains("day07/src/lib.rs"));
+        assert!(committed.contains(".aocr/day07.toml"));
+        assert!(!committed.contains("notes.txt"));
+        assert!(run(root, &["tag"])?.contains("day07-part2"));
+
+        // Solving it again with nothing changed neither commits nor tags twice
+        let head = run(root, &["rev-parse", "HEAD"])?;
+        assert_eq!(commit_solved(root, &config, 7, 2, None)?, None);
+        assert_eq!(run(root, &["rev-parse", "HEAD"])?, head);
+
+        // A changed solution is committed, the tag stays on the first commit
+        std::fs::write(root.join("day07/src/lib.rs"), "// solved faster\n")?;
+        assert!(commit_solved(root, &config, 7, 2, None)?.is_some());
+        assert_eq!(run(root, &["rev-parse", "day07-part2^{commit}"])?, head);
+        Ok(())
+    }
+}
@@ -0,0 +1,189 @@
+// src/history.rs
+use crate::git;
+use crate::runner::{self, Solution};
+use crate::state::{self, STATE_DIR};
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// One run of a part, as remembered in the day's history.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Entry {
+    /// Unix timestamp of the run
+    pub timestamp: u64,
+    pub part: u8,
+    /// Name of the input the part ran on
+    pub input: String,
+    pub answer: String,
+    pub runtime_nanos: u64,
+    /// Short hash of the checked out commit, with `*` if the day had uncommitted changes
+    pub commit: Option<String>,
+    /// Cargo profile the solution was built with
+    pub profile: String,
+}
+
+impl Entry {
+    pub fn runtime(&self) -> Duration {
+        Duration::from_nanos(self.runtime_nanos)
+    }
+
+    /// The entry as one table row, see `header`.
+    pub fn render(&self) -> String {
+        row(
+            &format_timestamp(self.timestamp),
+            &self.part.to_string(),
+            &self.input,
+            &self.answer,
+            &format!("{:.1?}", self.runtime()),
+            self.commit.as_deref().unwrap_or("-"),
+            &self.profile,
+        )
+    }
+}
+
+/// Column titles matching `Entry::render`.
+pub fn header() -> String {
+    row(
+        "When (UTC)",
+        "P",
+        "Input",
+        "Answer",
+        "Runtime",
+        "Commit",
+        "Profile",
+    )
+}
+
+fn row(
+    when: &str,
+    part: &str,
+    input: &str,
+    answer: &str,
+    runtime: &str,
+    commit: &str,
+    profile: &str,
+) -> String {
+    format!(
+        "{:<16}  {}  {:<10} {:>16} {:>10}  {:<9} {}",
+        when, part, input, answer, runtime, commit, profile
+    )
+}
+
+/// Path of the history of `day`, relative to the workspace root. One JSON entry per
+/// line, so a run only appends.
+pub fn path(day: u8) -> PathBuf {
+    PathBuf::from(format!("{}/history/day{:02}.jsonl", STATE_DIR, day))
+}
+
+/// Every run of `day`, oldest first.
+pub fn load(root: &Path, day: u8) -> Result<Vec<Entry>> {
+    let path = root.join(path(day));
+    let contents = match fs::read_to_string(&path) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e).with_context(|| format!("Failed to read {:?}", path)),
+    };
+    contents
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            serde_json::from_str(line).with_context(|| format!("Failed to parse {:?}", path))
+        })
+        .collect()
+}
+
+pub fn append(root: &Path, day: u8, entry: &Entry) -> Result<()> {
+    let path = root.join(path(day));
+    fs::create_dir_all(path.parent().unwrap())?;
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .with_context(|| format!("Failed to open {:?}", path))?;
+    writeln!(file, "{}", serde_json::to_string(entry)?)
+        .with_context(|| format!("Failed to write {:?}", path))
+}
+
+/// Add a run to the day's history, noting the commit it was built from.
+pub fn record(root: &Path, day: u8, part: u8, input: &str, solution: &Solution) -> Result<()> {
+    let commit = git::run(root, &["rev-parse", "--short", "HEAD"])
+        .ok()
+        .map(|hash| {
+            let dirty = git::run(
+                root,
+                &["status", "--porcelain", "--", &format!("day{:02}", day)],
+            )
+            .is_ok_and(|status| !status.trim().is_empty());
+            format!("{}{}", hash.trim(), if dirty { "*" } else { "" })
+        });
+    append(
+        root,
+        day,
+        &Entry {
+            timestamp: state::now(),
+            part,
+            input: input.to_string(),
+            answer: solution.answer.to_string(),
+            runtime_nanos: solution.duration.as_nanos() as u64,
+            commit,
+            profile: runner::PROFILE.to_string(),
+        },
+    )
+}
+
+/// Format a Unix timestamp as `YYYY-MM-DD HH:MM` in UTC.
+fn format_timestamp(timestamp: u64) -> String {
+    let (days, seconds) = (timestamp / 86_400, timestamp % 86_400);
+    // Civil date from days since 1970-01-01, the inverse of `aoc::days_from_civil`
+    let z = days as i64 + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    format!(
+        "{:04}-{:02}-{:02} {:02}:{:02}",
+        year,
+        month,
+        day,
+        seconds / 3_600,
+        seconds % 3_600 / 60
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_history_round_trip() -> Result<()> {
+        let dir = TempDir::new()?;
+        assert!(load(dir.path(), 5)?.is_empty());
+
+        let solution = Solution {
+            answer: 42,
+            duration: Duration::from_micros(1_500),
+        };
+        record(dir.path(), 5, 1, "mine", &solution)?;
+        record(dir.path(), 5, 2, "alice", &solution)?;
+
+        let entries = load(dir.path(), 5)?;
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[1].input, "alice");
+        assert_eq!(entries[0].answer, "42");
+        // Not a git repository
+        assert_eq!(entries[0].commit, None);
+        assert_eq!(entries[0].runtime(), Duration::from_micros(1_500));
+
+        // 2024-12-01T05:00:00Z
+        assert_eq!(format_timestamp(1_733_029_200), "2024-12-01 05:00");
+        Ok(())
+    }
+}
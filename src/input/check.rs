@@ -0,0 +1,112 @@
+// src/input/check.rs
+use super::DEFAULT_NAME;
+use crate::state::DayState;
+use anyhow::{bail, Result};
+use std::path::Path;
+
+/// The placeholder `aocr run` puts in the editor when it asks for an input.
+pub const EDITOR_PROMPT: &str = "# Enter your input here";
+
+/// An input after normalization, with what was changed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Checked {
+    pub input: String,
+    pub warnings: Vec<String>,
+}
+
+/// Fix the usual copy and paste mistakes: CRLF line endings, the editor prompt left on
+/// the first line and extra trailing newlines.
+pub fn normalize(raw: &str) -> Checked {
+    let mut warnings = Vec::new();
+
+    let mut input = if raw.contains("\r\n") {
+        warnings.push(String::from("Converted CRLF line endings to LF"));
+        raw.replace("\r\n", "\n")
+    } else {
+        raw.to_string()
+    };
+
+    if let Some(rest) = input.strip_prefix(EDITOR_PROMPT) {
+        warnings.push(format!("Removed the {:?} line", EDITOR_PROMPT));
+        input = rest.strip_prefix('\n').unwrap_or(rest).to_string();
+    }
+
+    let body = input.trim_end_matches('\n').len();
+    let extra = (input.len() - body).saturating_sub(1);
+    if extra > 0 {
+        warnings.push(format!("Removed {} extra trailing newline(s)", extra));
+        input.truncate(body + 1);
+    }
+
+    Checked { input, warnings }
+}
+
+/// Normalize the input `name` of `day` unless the day preserves its input byte for
+/// byte. An empty input is only refused as the default one of a day that does not
+/// preserve its input, since elsewhere it is likely an edge case on purpose.
+pub fn prepare(root: &Path, day: u8, name: &str, raw: &str) -> Result<Checked> {
+    let preserve = DayState::load(root, day)?.preserve_input;
+    let mut checked = if preserve {
+        Checked {
+            input: raw.to_string(),
+            warnings: Vec::new(),
+        }
+    } else {
+        normalize(raw)
+    };
+    if checked.input.trim().is_empty() {
+        if name == DEFAULT_NAME && !preserve {
+            bail!("The input for day {} is empty", day);
+        }
+        checked.warnings.push(String::from("The input is empty"));
+    }
+    Ok(checked)
+}
+
+/// Turn normalization of the inputs of `day` off, or back on when `preserve` is false.
+pub fn set_preserve(root: &Path, day: u8, preserve: bool) -> Result<()> {
+    let mut state = DayState::load(root, day)?;
+    state.preserve_input = preserve;
+    state.save(root, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_normalize() -> Result<()> {
+        let checked = normalize("# Enter your input here\r\n1 2\r\n3 4\r\n\r\n");
+        assert_eq!(checked.input, "1 2\n3 4\n");
+        assert_eq!(checked.warnings.len(), 3);
+        assert!(normalize("1 2\n3 4\n").warnings.is_empty());
+        assert!(normalize("1 2").warnings.is_empty());
+
+        let dir = TempDir::new()?;
+        assert!(prepare(dir.path(), 1, DEFAULT_NAME, "# Enter your input here\n\n").is_err());
+        // An empty edge case is fine under its own name
+        let empty = prepare(dir.path(), 1, "edge-empty", "\n")?;
+        assert_eq!(empty.input, "\n");
+        assert_eq!(empty.warnings, ["The input is empty"]);
+
+        set_preserve(dir.path(), 1, true)?;
+        assert_eq!(
+            prepare(dir.path(), 1, DEFAULT_NAME, "a\r\n\n")?.input,
+            "a\r\n\n"
+        );
+        assert!(prepare(dir.path(), 1, DEFAULT_NAME, "")?.warnings[0].contains("empty"));
+
+        // Turning it back off normalizes again and keeps the rest of the state
+        let mut state = DayState::load(dir.path(), 1)?;
+        state.tests_passed = Some(true);
+        state.save(dir.path(), 1)?;
+        set_preserve(dir.path(), 1, false)?;
+        assert_eq!(
+            prepare(dir.path(), 1, DEFAULT_NAME, "a\r\n\n")?.input,
+            "a\n"
+        );
+        assert_eq!(DayState::load(dir.path(), 1)?.tests_passed, Some(true));
+        Ok(())
+    }
+}
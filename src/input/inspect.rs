@@ -0,0 +1,245 @@
+// src/input/inspect.rs
+use crate::input;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+/// Most characters the histogram lists.
+const HISTOGRAM_LIMIT: usize = 16;
+
+/// The integers found in an input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Numbers {
+    pub count: usize,
+    pub min: i128,
+    pub max: i128,
+    /// Most integers on one line
+    pub per_line: usize,
+}
+
+/// Structure of an input, to eyeball before writing a parser.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InputStats {
+    pub lines: usize,
+    pub min_width: usize,
+    pub max_width: usize,
+    /// Blank-line separated groups of lines
+    pub sections: usize,
+    /// Characters by how often they appear, most common first
+    pub histogram: Vec<(char, usize)>,
+    pub numbers: Option<Numbers>,
+    pub trailing_newlines: usize,
+}
+
+impl InputStats {
+    pub fn new(input: &str) -> Self {
+        let body = input.trim_end_matches('\n');
+        let lines: Vec<&str> = if body.is_empty() {
+            Vec::new()
+        } else {
+            body.split('\n').collect()
+        };
+        let widths: Vec<usize> = lines.iter().map(|line| line.chars().count()).collect();
+
+        let mut sections = 0;
+        let mut in_section = false;
+        for line in &lines {
+            let blank = line.trim().is_empty();
+            if !blank && !in_section {
+                sections += 1;
+            }
+            in_section = !blank;
+        }
+
+        let mut counts: BTreeMap<char, usize> = BTreeMap::new();
+        for c in body.chars().filter(|&c| c != '\n') {
+            *counts.entry(c).or_default() += 1;
+        }
+        let mut histogram: Vec<(char, usize)> = counts.into_iter().collect();
+        histogram.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+
+        let mut numbers: Option<Numbers> = None;
+        for line in &lines {
+            let found = integers(line);
+            if found.is_empty() {
+                continue;
+            }
+            let stats = numbers.get_or_insert(Numbers {
+                count: 0,
+                min: found[0],
+                max: found[0],
+                per_line: 0,
+            });
+            stats.count += found.len();
+            stats.per_line = stats.per_line.max(found.len());
+            for n in found {
+                stats.min = stats.min.min(n);
+                stats.max = stats.max.max(n);
+            }
+        }
+
+        Self {
+            lines: lines.len(),
+            min_width: widths.iter().copied().min().unwrap_or(0),
+            max_width: widths.iter().copied().max().unwrap_or(0),
+            sections,
+            histogram,
+            numbers,
+            trailing_newlines: input.len() - body.len(),
+        }
+    }
+
+    /// Statistics of the stored input `name` of a part, if there is one.
+    pub fn load(root: &Path, day: u8, part: u8, name: &str) -> Option<Self> {
+        fs::read_to_string(root.join(input::named_path(day, part, name)))
+            .ok()
+            .map(|input| Self::new(&input))
+    }
+
+    /// Width and height when every line is as wide as the others.
+    pub fn grid(&self) -> Option<(usize, usize)> {
+        (self.lines > 1 && self.min_width == self.max_width && self.max_width > 0)
+            .then_some((self.max_width, self.lines))
+    }
+
+    /// Anything about the input that is likely a mistake.
+    pub fn warnings(&self) -> Vec<String> {
+        let mut warnings = Vec::new();
+        if self.lines == 0 {
+            warnings.push(String::from("The input is empty"));
+        }
+        if self.trailing_newlines > 1 {
+            warnings.push(format!(
+                "Ends with {} newlines; `lines()` sees {} blank line(s)",
+                self.trailing_newlines,
+                self.trailing_newlines - 1
+            ));
+        }
+        warnings
+    }
+
+    /// The statistics as text lines, without the warnings.
+    pub fn render(&self) -> Vec<String> {
+        let mut lines = vec![format!("Lines:     {}", self.lines)];
+        if self.min_width == self.max_width {
+            lines.push(format!("Width:     {}", self.max_width));
+        } else {
+            lines.push(format!(
+                "Width:     {} to {}",
+                self.min_width, self.max_width
+            ));
+        }
+        match self.grid() {
+            Some((width, height)) => lines.push(format!("Grid:      {}x{}", width, height)),
+            None => lines.push(String::from("Grid:      no, line widths differ")),
+        }
+        lines.push(format!("Sections:  {}", self.sections));
+        lines.push(format!(
+            "Ends with: {}",
+            match self.trailing_newlines {
+                0 => "no newline",
+                1 => "a newline",
+                _ => "several newlines",
+            }
+        ));
+        match &self.numbers {
+            Some(numbers) => lines.push(format!(
+                "Numbers:   {} from {} to {}, at most {} per line",
+                numbers.count, numbers.min, numbers.max, numbers.per_line
+            )),
+            None => lines.push(String::from("Numbers:   none")),
+        }
+
+        lines.push(String::new());
+        lines.push(format!("Characters ({} distinct):", self.histogram.len()));
+        for (c, count) in self.histogram.iter().take(HISTOGRAM_LIMIT) {
+            let shown = match c {
+                ' ' => String::from("space"),
+                '\t' => String::from("tab"),
+                '\r' => String::from("\\r"),
+                c => format!("'{}'", c),
+            };
+            lines.push(format!("  {:>7} {}", shown, count));
+        }
+        if self.histogram.len() > HISTOGRAM_LIMIT {
+            lines.push(format!(
+                "  ... and {} more",
+                self.histogram.len() - HISTOGRAM_LIMIT
+            ));
+        }
+        lines
+    }
+}
+
+/// The integers in a line, with a `-` counting as a sign only when it does not follow
+/// a letter or digit, as in `x=-3` but not `2024-12-01`.
+fn integers(line: &str) -> Vec<i128> {
+    let bytes = line.as_bytes();
+    let mut found = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        if !bytes[i].is_ascii_digit() {
+            i += 1;
+            continue;
+        }
+        let start = i;
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+        let negative = start > 0
+            && bytes[start - 1] == b'-'
+            && (start < 2 || !bytes[start - 2].is_ascii_alphanumeric());
+        if let Ok(n) = line[start..i].parse::<i128>() {
+            found.push(if negative { -n } else { n });
+        }
+    }
+    found
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_stats() {
+        let stats = InputStats::new("#.#\n..#\n\n-3 x=-12 2024-12-01\n\n");
+        assert_eq!(stats.lines, 4);
+        assert_eq!((stats.min_width, stats.max_width), (0, 19));
+        assert_eq!(stats.sections, 2);
+        assert_eq!(stats.trailing_newlines, 2);
+        assert_eq!(stats.grid(), None);
+        assert_eq!(
+            stats.numbers,
+            Some(Numbers {
+                count: 5,
+                min: -12,
+                max: 2024,
+                per_line: 5,
+            })
+        );
+        assert_eq!(stats.histogram[0], ('-', 4));
+        assert_eq!(stats.warnings().len(), 1);
+
+        let grid = InputStats::new("#.#\n..#\n");
+        assert_eq!(grid.grid(), Some((3, 2)));
+        assert_eq!(grid.histogram, vec![('#', 3), ('.', 3)]);
+        assert!(InputStats::new("").warnings()[0].contains("empty"));
+    }
+
+    #[test]
+    fn test_load_named_input() -> std::io::Result<()> {
+        let dir = tempfile::TempDir::new()?;
+        fs::create_dir_all(dir.path().join(input::INPUTS_DIR))?;
+        fs::write(dir.path().join(input::path(4, 1)), "#.#\n..#\n")?;
+        fs::write(dir.path().join(input::named_path(4, 1, "alice")), "1 2 3\n")?;
+
+        let mine = InputStats::load(dir.path(), 4, 1, input::DEFAULT_NAME).unwrap();
+        assert_eq!(mine.grid(), Some((3, 2)));
+        // Switching the name switches the input that is inspected
+        let alice = InputStats::load(dir.path(), 4, 1, "alice").unwrap();
+        assert_eq!(alice.lines, 1);
+        assert_eq!(alice.numbers.map(|numbers| numbers.count), Some(3));
+        assert_eq!(InputStats::load(dir.path(), 4, 1, "bob"), None);
+        Ok(())
+    }
+}
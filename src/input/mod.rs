@@ -0,0 +1,105 @@
+// src/input/mod.rs
+pub mod check;
+pub mod inspect;
+
+use anyhow::{bail, Result};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Directory holding puzzle inputs, relative to the workspace root.
+pub const INPUTS_DIR: &str = "inputs";
+
+/// Name of your own input, the one stored by `path`.
+pub const DEFAULT_NAME: &str = "mine";
+
+/// Name under which the example from the puzzle description is listed, see `example_path`.
+pub const EXAMPLE_NAME: &str = "example";
+
+/// Where the input for a day and part is stored, relative to the workspace root.
+pub fn path(day: u8, part: u8) -> PathBuf {
+    PathBuf::from(format!("{}/day{:02}_part{}.txt", INPUTS_DIR, day, part))
+}
+
+/// Where a named input is stored, relative to the workspace root. Other inputs than
+/// your own get the name as a suffix, e.g. `inputs/day05_part1_alice.txt`.
+pub fn named_path(day: u8, part: u8, name: &str) -> PathBuf {
+    if name == DEFAULT_NAME {
+        path(day, part)
+    } else {
+        PathBuf::from(format!(
+            "{}/day{:02}_part{}_{}.txt",
+            INPUTS_DIR, day, part, name
+        ))
+    }
+}
+
+/// Check that `name` can be part of a file name.
+pub fn validate_name(name: &str) -> Result<()> {
+    let valid = name
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+    if name.is_empty() || !valid {
+        bail!(
+            "Invalid input name {:?}, use letters, digits, '-' and '_'",
+            name
+        );
+    }
+    Ok(())
+}
+
+/// The names of the stored inputs of a part, your own first and the others sorted.
+pub fn names(root: &Path, day: u8, part: u8) -> Vec<String> {
+    let prefix = format!("day{:02}_part{}_", day, part);
+    let mut names: Vec<String> = fs::read_dir(root.join(INPUTS_DIR))
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter_map(|entry| {
+            let file_name = entry.file_name().into_string().ok()?;
+            let name = file_name.strip_prefix(&prefix)?.strip_suffix(".txt")?;
+            validate_name(name).ok()?;
+            Some(name.to_string())
+        })
+        .collect();
+    names.sort();
+    names.insert(0, DEFAULT_NAME.to_string());
+    names
+}
+
+/// Where the example input from the puzzle description is stored, relative to the workspace root.
+pub fn example_path(day: u8, part: u8) -> PathBuf {
+    named_path(day, part, EXAMPLE_NAME)
+}
+
+/// Where older versions of `aocr run` stored inputs, migrated by `aocr init --upgrade`.
+pub fn legacy_path(day: u8, part: u8) -> PathBuf {
+    PathBuf::from(format!("{}/day{:02}/part{}.txt", INPUTS_DIR, day, part))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_named_inputs() -> Result<()> {
+        let dir = TempDir::new()?;
+        assert_eq!(names(dir.path(), 5, 1), ["mine"]);
+
+        for name in ["mine", "alice", "edge-empty"] {
+            let path = dir.path().join(named_path(5, 1, name));
+            fs::create_dir_all(path.parent().unwrap())?;
+            fs::write(path, "1\n")?;
+        }
+        fs::write(dir.path().join(example_path(5, 1)), "1\n")?;
+        fs::write(dir.path().join(named_path(5, 2, "bob")), "1\n")?;
+
+        assert_eq!(
+            names(dir.path(), 5, 1),
+            ["mine", "alice", "edge-empty", "example"]
+        );
+        assert_eq!(named_path(5, 1, "mine"), path(5, 1));
+        assert!(validate_name("../secret").is_err());
+        Ok(())
+    }
+}
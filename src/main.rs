@@ -1,18 +1,69 @@
+mod aoc;
 mod cli;
 mod commands;
+mod config;
+mod git;
+mod history;
+mod input;
 mod runner;
+mod state;
+mod template;
 mod tui;
+mod workspace;
 
 use clap::Parser;
-use cli::{Cli, Commands};
+use cli::{Cli, Commands, CommonCommands, InputCommands};
 
 fn main() -> anyhow::Result<()> {
     let cli = Cli::parse();
 
     match cli.command {
-        Commands::Init { path } => commands::init::execute(path)?,
-        Commands::Run { day, part, input } => commands::run::execute(day, part, input)?,
+        Commands::Init {
+            path,
+            template,
+            year,
+            empty,
+            common,
+            upgrade,
+            dry_run,
+            no_git,
+        } => commands::init::execute(
+            path,
+            commands::init::InitOptions {
+                template,
+                year,
+                empty,
+                common,
+                upgrade,
+                dry_run,
+                no_git,
+            },
+        )?,
+        Commands::New { day } => commands::new::execute(day)?,
+        Commands::Examples { day, save, tests } => commands::examples::execute(day, save, tests)?,
+        Commands::Leaderboard { id, file, day } => commands::leaderboard::execute(id, file, day)?,
+        Commands::Puzzle { day, refresh } => commands::puzzle::execute(day, refresh)?,
+        Commands::Run {
+            day,
+            part,
+            input,
+            input_name,
+        } => commands::run::execute(day, part, input, input_name)?,
+        Commands::Submit { day, part, answer } => commands::submit::execute(day, part, answer)?,
+        Commands::Stats => commands::stats::execute()?,
+        Commands::Crosscheck { day } => commands::crosscheck::execute(day)?,
+        Commands::Compare {
+            day,
+            part,
+            input_name,
+        } => commands::compare::execute(day, part, input_name)?,
         Commands::Watch => commands::watch::execute()?,
+        Commands::Common {
+            command: CommonCommands::Add { module },
+        } => commands::common::execute_add(&module)?,
+        Commands::Input {
+            command: InputCommands::Preserve { day, off },
+        } => commands::input::execute_preserve(day, off)?,
     }
 
     Ok(())
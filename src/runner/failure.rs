@@ -0,0 +1,106 @@
+// src/runner/failure.rs
+use super::DebugOutput;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+/// Lines of source shown on each side of the panicking one.
+const CONTEXT_LINES: u32 = 2;
+
+/// Where and why a solution panicked, as the runner's panic hook wrote it to `panic.txt`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Panic {
+    pub message: String,
+    /// Source file, relative to the workspace root for the days' crates
+    pub file: String,
+    pub line: u32,
+    pub column: u32,
+}
+
+impl Panic {
+    /// Parse the `file:line:col` location on the first line and the message after it.
+    pub fn parse(text: &str) -> Option<Self> {
+        let (location, message) = text.split_once('\n').unwrap_or((text, ""));
+        let mut parts = location.rsplitn(3, ':');
+        let column = parts.next()?.parse().ok()?;
+        let line = parts.next()?.parse().ok()?;
+        Some(Self {
+            message: message.to_string(),
+            file: parts.next()?.to_string(),
+            line,
+            column,
+        })
+    }
+
+    /// The lines around the panicking one with their numbers, if the file can be read.
+    pub fn source(&self, root: &Path) -> Option<Vec<(u32, String)>> {
+        let source = fs::read_to_string(root.join(&self.file)).ok()?;
+        let first = self.line.saturating_sub(CONTEXT_LINES).max(1);
+        Some(
+            (first..)
+                .zip(source.lines().skip(first as usize - 1))
+                .take_while(|(number, _)| *number <= self.line + CONTEXT_LINES)
+                .map(|(number, line)| (number, line.to_string()))
+                .collect(),
+        )
+    }
+}
+
+/// A solution that did not finish, returned by `Runner::run_functions` inside the
+/// `anyhow::Error` so callers can downcast to it for the details.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Failure {
+    /// The function that was running
+    pub function: String,
+    /// How the process ended, e.g. `exit status: 101`
+    pub status: String,
+    pub panic: Option<Panic>,
+    /// What the solution printed, with the backtrace when `RUST_BACKTRACE` is set
+    pub output: DebugOutput,
+}
+
+impl Failure {
+    /// The panicking source lines and the debug output, to show below the error itself.
+    /// With `color`, the panicking line is marked in red for the TUI's output pane.
+    pub fn render(&self, root: &Path, color: bool) -> String {
+        let mut rendered = String::new();
+        if let Some((panic, source)) = self
+            .panic
+            .as_ref()
+            .and_then(|panic| Some((panic, panic.source(root)?)))
+        {
+            let width = (panic.line + CONTEXT_LINES).to_string().len();
+            rendered.push_str(&format!(
+                " --> {}:{}:{}\n",
+                panic.file, panic.line, panic.column
+            ));
+            for (number, line) in source {
+                let row = format!("{:>width$} | {}", number, line, width = width);
+                if number != panic.line {
+                    rendered.push_str(&format!("  {}\n", row));
+                } else if color {
+                    rendered.push_str(&format!("\x1b[1;31m> {}\x1b[0m\n", row));
+                } else {
+                    rendered.push_str(&format!("> {}\n", row));
+                }
+            }
+        }
+        rendered.push_str(&self.output.render());
+        rendered
+    }
+}
+
+impl fmt::Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.panic {
+            Some(panic) => write!(
+                f,
+                "{} panicked at {}:{}:{}: {}",
+                self.function, panic.file, panic.line, panic.column, panic.message
+            ),
+            None => write!(f, "{} failed with {}", self.function, self.status),
+        }
+    }
+}
+
+impl std::error::Error for Failure {}
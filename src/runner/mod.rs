@@ -1,11 +1,84 @@
 // src/runner/mod.rs
-use anyhow::{Context, Result};
+mod failure;
+
+pub use failure::{Failure, Panic};
+
+use anyhow::{bail, Context, Result};
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::process::Command;
+use std::time::Duration;
+
+/// Cargo profile the runner builds solutions with.
+pub const PROFILE: &str = "dev";
+
+/// The outcome of running one part of a day.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Solution {
+    pub answer: usize,
+    /// Time spent inside the part function, excluding the build
+    pub duration: Duration,
+}
+
+/// How often `Runner::bench` runs a part.
+pub const BENCH_RUNS: usize = 10;
+
+/// The runtimes of a part over several runs on the same input.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Bench {
+    pub answer: usize,
+    /// Runtime of each run, fastest first
+    pub runtimes: Vec<Duration>,
+}
+
+impl Bench {
+    pub fn render(&self) -> String {
+        let total: Duration = self.runtimes.iter().sum();
+        format!(
+            "{} over {} runs: min {:.1?}, median {:.1?}, mean {:.1?}",
+            self.answer,
+            self.runtimes.len(),
+            self.runtimes[0],
+            self.runtimes[self.runtimes.len() / 2],
+            total / self.runtimes.len() as u32
+        )
+    }
+}
+
+/// What a solution printed itself while it ran, kept apart from its answers.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DebugOutput {
+    pub stdout: String,
+    pub stderr: String,
+}
+
+impl DebugOutput {
+    /// The output as a section per stream, empty when the solution printed nothing.
+    pub fn render(&self) -> String {
+        let mut rendered = String::new();
+        for (stream, text) in [("stdout", &self.stdout), ("stderr", &self.stderr)] {
+            if !text.trim().is_empty() {
+                rendered.push_str(&format!("Debug output ({}):\n", stream));
+                rendered.push_str(text.trim_end());
+                rendered.push('\n');
+            }
+        }
+        rendered
+    }
+}
+
+/// The outcome of `cargo test` for a day.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TestReport {
+    pub passed: bool,
+    /// What cargo printed to stderr
+    pub output: String,
+}
 
 pub struct Runner {
     runner_dir: PathBuf,
+    /// `RUST_BACKTRACE` for solution runs, inherited from the environment when unset
+    backtrace: Option<String>,
 }
 impl Default for Runner {
     fn default() -> Self {
@@ -20,12 +93,28 @@ impl Runner {
     {
         Self {
             runner_dir: path.as_ref().to_path_buf(),
+            backtrace: None,
+        }
+    }
+
+    pub fn with_backtrace(mut self, backtrace: Option<String>) -> Self {
+        self.backtrace = backtrace;
+        self
+    }
+
+    /// The workspace containing the runner crate.
+    fn workspace_dir(&self) -> &Path {
+        match self.runner_dir.parent() {
+            Some(parent) if !parent.as_os_str().is_empty() => parent,
+            _ => Path::new("."),
         }
     }
 
     pub fn check_day(&self, day: u8) -> String {
         let output = Command::new("cargo")
+            .current_dir(self.workspace_dir())
             .arg("check")
+            .arg("--color=always")
             .arg("-p")
             .arg(format!("day{:02}", day))
             .output();
@@ -35,21 +124,100 @@ impl Runner {
         }
     }
 
-    pub fn test_day(&self, day: u8) -> String {
+    pub fn test_day(&self, day: u8) -> TestReport {
         let output = Command::new("cargo")
+            .current_dir(self.workspace_dir())
             .arg("test")
+            .arg("--color=always")
             .arg("-p")
             .arg(format!("day{:02}", day))
             .output();
         match output {
-            Err(e) => e.to_string(),
-            Ok(output) => String::from_utf8_lossy(&output.stderr).into_owned(),
+            Err(e) => TestReport {
+                passed: false,
+                output: e.to_string(),
+            },
+            Ok(output) => TestReport {
+                passed: output.status.success(),
+                output: String::from_utf8_lossy(&output.stderr).into_owned(),
+            },
         }
     }
 
-    pub fn run_day(&self, day: u8, part: u8, input: &str) -> Result<usize> {
+    pub fn run_day(&self, day: u8, part: u8, input: &str) -> Result<(Solution, DebugOutput)> {
+        let (solutions, output) = self.run_functions(day, &[format!("part{}", part)], input)?;
+        Ok((solutions[0], output))
+    }
+
+    /// Run a part `runs` times in one process, so the build is paid for once.
+    pub fn bench(
+        &self,
+        day: u8,
+        part: u8,
+        input: &str,
+        runs: usize,
+    ) -> Result<(Bench, DebugOutput)> {
+        let functions = vec![format!("part{}", part); runs.max(1)];
+        let (solutions, output) = self.run_functions(day, &functions, input)?;
+        let mut runtimes: Vec<Duration> = solutions.iter().map(|s| s.duration).collect();
+        runtimes.sort_unstable();
+        let bench = Bench {
+            answer: solutions[0].answer,
+            runtimes,
+        };
+        Ok((bench, output))
+    }
+
+    /// The alternative implementations of a part in the day's `lib.rs`: top-level public
+    /// functions named like `part1_fast` with the same parameter and return types as
+    /// the part itself, in the order they appear.
+    pub fn variants(&self, day: u8, part: u8) -> Result<Vec<String>> {
+        let lib = self
+            .workspace_dir()
+            .join(format!("day{:02}/src/lib.rs", day));
+        let source =
+            fs::read_to_string(&lib).with_context(|| format!("Failed to read {:?}", lib))?;
+        let signatures = signatures(&source);
+        let name = format!("part{}", part);
+        let Some(main) = signatures.iter().find(|signature| signature.name == name) else {
+            return Ok(Vec::new());
+        };
+        let prefix = format!("{}_", name);
+        Ok(signatures
+            .iter()
+            .filter(|signature| {
+                let valid = signature.name.strip_prefix(&prefix).is_some_and(|suffix| {
+                    !suffix.is_empty()
+                        && suffix
+                            .chars()
+                            .all(|c| c.is_ascii_alphanumeric() || c == '_')
+                });
+                valid && signature.params == main.params && signature.output == main.output
+            })
+            .map(|signature| signature.name.clone())
+            .collect())
+    }
+
+    /// Run the named functions of a day's crate one after the other on the same input.
+    /// The answers come back through `results.txt`, so the functions are free to print.
+    /// A function that panics or exits fails the run with a `Failure`.
+    pub fn run_functions(
+        &self,
+        day: u8,
+        functions: &[String],
+        input: &str,
+    ) -> Result<(Vec<Solution>, DebugOutput)> {
         // Create temporary runner project
         let day_str = format!("day{:02}", day);
+        if !self.workspace_dir().join(&day_str).exists() {
+            bail!(
+                "{} does not exist yet, create it with `aocr new {}`",
+                day_str,
+                day
+            );
+        }
+        fs::create_dir_all(self.runner_dir.join("src"))?;
+
         // Write input file
         let input_path = self.runner_dir.join("input.txt");
         fs::write(&input_path, input)?;
@@ -68,41 +236,323 @@ edition = "2021"
         );
         fs::write(self.runner_dir.join("Cargo.toml"), cargo_toml)?;
 
-        // Create src directory
-        fs::create_dir_all(self.runner_dir.join("src"))?;
-
-        // Create main.rs
+        // Create main.rs, timing each function on its own
+        let calls: String = functions
+            .iter()
+            .map(|function| {
+                format!(
+                    r#"
+    let start = std::time::Instant::now();
+    let answer = {}::{}(input);
+    let elapsed = start.elapsed();
+    writeln!(results, "{{}}", answer).expect("Failed to write the answer");
+    writeln!(results, "{{}}", elapsed.as_nanos()).expect("Failed to write the runtime");
+"#,
+                    day_str, function
+                )
+            })
+            .collect();
         let main_rs = format!(
-            r#"fn main() {{
-    println!("{{}}", {}::part{}(include_str!("../input.txt")));
-}}"#,
-            day_str, part
+            r#"use std::io::Write;
+
+fn main() {{
+    // Leave where and why a solution panicked for aocr, then report it as usual
+    let default_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {{
+        let payload = info.payload();
+        let message = payload
+            .downcast_ref::<&str>()
+            .map(|message| message.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_default();
+        if let Some(location) = info.location() {{
+            let _ = std::fs::write(
+                concat!(env!("CARGO_MANIFEST_DIR"), "/panic.txt"),
+                format!("{{}}:{{}}:{{}}\n{{}}", location.file(), location.line(), location.column(), message),
+            );
+        }}
+        default_hook(info);
+    }}));
+
+    let input = include_str!("../input.txt");
+    let mut results = std::fs::File::create(concat!(env!("CARGO_MANIFEST_DIR"), "/results.txt"))
+        .expect("Failed to create results.txt");{}}}"#,
+            calls
         );
         fs::write(self.runner_dir.join("src").join("main.rs"), main_rs)?;
 
-        // Build and run
-        Command::new("cargo")
+        // Build, then run the binary itself so cargo's own messages stay out of the output
+        let build = Command::new("cargo")
             .arg("build")
+            .arg("--message-format=json-render-diagnostics")
             .current_dir(&self.runner_dir)
             .output()
             .context("Failed to build runner")?;
+        let executable = String::from_utf8_lossy(&build.stdout)
+            .lines()
+            .filter_map(|line| serde_json::from_str::<serde_json::Value>(line).ok())
+            .filter(|message| message["target"]["name"] == "aoc-runner")
+            .find_map(|message| message["executable"].as_str().map(PathBuf::from));
+        let Some(executable) = executable else {
+            bail!(
+                "Failed to build runner:\n{}",
+                String::from_utf8_lossy(&build.stderr)
+            );
+        };
+
+        // Answers and panics from an earlier run must not pass for this one's
+        let results_path = self.runner_dir.join("results.txt");
+        let panic_path = self.runner_dir.join("panic.txt");
+        for path in [&results_path, &panic_path] {
+            if path.exists() {
+                fs::remove_file(path)?;
+            }
+        }
+        let mut command = Command::new(executable);
+        command.current_dir(&self.runner_dir);
+        if let Some(backtrace) = &self.backtrace {
+            command.env("RUST_BACKTRACE", backtrace);
+        }
+        let output = command.output().context("Failed to run solution")?;
+        let debug = DebugOutput {
+            stdout: String::from_utf8_lossy(&output.stdout).into_owned(),
+            stderr: String::from_utf8_lossy(&output.stderr).into_owned(),
+        };
+
+        let results = fs::read_to_string(&results_path).unwrap_or_default();
+        if !output.status.success() {
+            // Each finished function left two lines
+            let finished = results.lines().count() / 2;
+            return Err(Failure {
+                function: functions[finished.min(functions.len() - 1)].clone(),
+                status: output.status.to_string(),
+                panic: fs::read_to_string(&panic_path)
+                    .ok()
+                    .and_then(|text| Panic::parse(&text)),
+                output: debug,
+            }
+            .into());
+        }
+        let mut lines = results.lines();
+        let mut solutions = Vec::new();
+        for _ in functions {
+            let answer = lines
+                .next()
+                .unwrap_or_default()
+                .trim()
+                .parse()
+                .context("Failed to parse result as usize")?;
+            let nanos = lines
+                .next()
+                .unwrap_or_default()
+                .trim()
+                .parse()
+                .context("Failed to parse runtime")?;
+            solutions.push(Solution {
+                answer,
+                duration: Duration::from_nanos(nanos),
+            });
+        }
+        Ok((solutions, debug))
+    }
+}
 
-        let output = Command::new("cargo")
-            .arg("run")
-            .current_dir(&self.runner_dir)
-            .output()
-            .context("Failed to run solution")?;
+/// A top-level public function, with its types as written and whitespace collapsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Signature {
+    name: String,
+    /// Parameter types, without the parameter names
+    params: Vec<String>,
+    /// Return type, empty for none
+    output: String,
+}
+
+impl Signature {
+    /// Parse a header such as `pub fn part1(input: &str) -> usize`, ending before its body.
+    fn parse(header: &str) -> Option<Self> {
+        let header = header.split_whitespace().collect::<Vec<_>>().join(" ");
+        let rest = header.strip_prefix("pub fn ")?;
+        let open = rest.find('(')?;
+        let close = open + matching_close(&rest[open..])?;
+        let params = split_top_level(&rest[open + 1..close])
+            .into_iter()
+            .map(|param| match param.split_once(':') {
+                Some((_, ty)) => ty.trim().to_string(),
+                None => param.trim().to_string(),
+            })
+            .filter(|ty| !ty.is_empty())
+            .collect();
+        let output = rest[close + 1..]
+            .split_whitespace()
+            .take_while(|word| *word != "where")
+            .collect::<Vec<_>>()
+            .join(" ");
+        Some(Self {
+            name: rest[..open].trim().to_string(),
+            params,
+            output: output.trim_start_matches("->").trim().to_string(),
+        })
+    }
+}
+
+/// The top-level `pub fn`s of a source file. Braces outside comments and literals are
+/// counted to tell them from methods and functions in modules, and a signature may span
+/// lines.
+fn signatures(source: &str) -> Vec<Signature> {
+    let mut found = Vec::new();
+    let mut depth = 0usize;
+    let mut pending: Option<String> = None;
+    for code in strip_comments_and_literals(source).lines() {
+        if depth == 0 && pending.is_none() && code.trim_start().starts_with("pub fn ") {
+            pending = Some(String::new());
+        }
+        if let Some(header) = pending.as_mut() {
+            header.push_str(code);
+            header.push(' ');
+            if let Some(end) = header.find(['{', ';']) {
+                found.extend(Signature::parse(&header[..end]));
+                pending = None;
+            }
+        }
+        depth = (depth + code.matches('{').count()).saturating_sub(code.matches('}').count());
+    }
+    found
+}
 
-        let result_str = String::from_utf8_lossy(&output.stdout).trim().to_string();
-        result_str
-            .parse()
-            .context("Failed to parse result as usize")
+/// The source with comments removed and string and char literals emptied, keeping its
+/// lines, so only the code's own braces are left.
+fn strip_comments_and_literals(source: &str) -> String {
+    let chars: Vec<char> = source.chars().collect();
+    let is_ident = |i: usize| chars[i].is_alphanumeric() || chars[i] == '_';
+    let mut code = String::with_capacity(source.len());
+    let mut i = 0;
+    while i < chars.len() {
+        let next = chars.get(i + 1).copied();
+        match chars[i] {
+            '/' if next == Some('/') => {
+                while i < chars.len() && chars[i] != '\n' {
+                    i += 1;
+                }
+                continue;
+            }
+            '/' if next == Some('*') => {
+                // Block comments nest
+                let mut depth = 0;
+                while i < chars.len() {
+                    if chars[i] == '/' && chars.get(i + 1) == Some(&'*') {
+                        depth += 1;
+                        i += 2;
+                    } else if chars[i] == '*' && chars.get(i + 1) == Some(&'/') {
+                        depth -= 1;
+                        i += 2;
+                        if depth == 0 {
+                            break;
+                        }
+                    } else {
+                        if chars[i] == '\n' {
+                            code.push('\n');
+                        }
+                        i += 1;
+                    }
+                }
+                continue;
+            }
+            // A raw string such as `r#"..."#` or `br"..."`, not an identifier ending in r
+            'r' if (i == 0
+                || !is_ident(i - 1)
+                || (chars[i - 1] == 'b' && (i == 1 || !is_ident(i - 2))))
+                && matches!(next, Some('"' | '#')) =>
+            {
+                let hashes = chars[i + 1..].iter().take_while(|&&c| c == '#').count();
+                if chars.get(i + 1 + hashes) == Some(&'"') {
+                    code.push_str("\"\"");
+                    i += hashes + 2;
+                    while i < chars.len() {
+                        if chars[i] == '"'
+                            && chars[i + 1..].iter().take_while(|&&c| c == '#').count() >= hashes
+                        {
+                            i += hashes + 1;
+                            break;
+                        }
+                        if chars[i] == '\n' {
+                            code.push('\n');
+                        }
+                        i += 1;
+                    }
+                    continue;
+                }
+            }
+            '"' => {
+                code.push_str("\"\"");
+                i += 1;
+                while i < chars.len() && chars[i] != '"' {
+                    if chars[i] == '\n' {
+                        code.push('\n');
+                    }
+                    i += if chars[i] == '\\' { 2 } else { 1 };
+                }
+                i += 1;
+                continue;
+            }
+            // A char literal such as `'{'` or `'\''`, unlike a lifetime such as `'a`
+            '\'' if next == Some('\\') || chars.get(i + 2) == Some(&'\'') => {
+                code.push_str("' '");
+                i += 2;
+                while i < chars.len() && chars[i] != '\'' {
+                    i += if chars[i] == '\\' { 2 } else { 1 };
+                }
+                i += 1;
+                continue;
+            }
+            _ => {}
+        }
+        code.push(chars[i]);
+        i += 1;
     }
+    code
+}
+
+/// Offset of the bracket closing the one `text` starts with.
+fn matching_close(text: &str) -> Option<usize> {
+    let mut depth = 0usize;
+    for (i, c) in text.char_indices() {
+        match c {
+            '(' | '[' | '<' => depth += 1,
+            ')' | ']' | '>' if !text[..i].ends_with('-') => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(i);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Split at the commas that are not nested in brackets.
+fn split_top_level(text: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let (mut depth, mut start) = (0usize, 0);
+    for (i, c) in text.char_indices() {
+        match c {
+            '(' | '[' | '<' => depth += 1,
+            ')' | ']' | '>' if !text[..i].ends_with('-') => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                parts.push(&text[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(&text[start..]);
+    parts
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::input::check;
     use std::{fs, path::Path};
     use tempfile::TempDir;
 
@@ -126,18 +576,88 @@ edition = "2021"
         // Create lib.rs with a simple implementation
         let lib_rs = r#"
 pub fn part1(input: &str) -> usize {
+    println!("parsing {}", input);
+    eprintln!("found 42");
     42
 }
 
 pub fn part2(input: &str) -> usize {
     84
 }
+
+pub fn part2_fast(input: &str) -> usize {
+    84
+}
+
+pub fn part2_helper_for(_: &str) {}
+
+pub fn part1_broken(input: &str) -> usize {
+    panic!("no answer for {:?}", input)
+}
+
+pub fn part2_parse(input: &str) -> Vec<usize> {
+    Vec::new()
+}
+
+    pub fn part2_multiline(
+        input: &str, // formatted by hand
+    ) -> usize {
+        84
+    }
+
+mod inner {
+    pub fn part2_nested(input: &str) -> usize {
+        84
+    }
+}
 "#;
         fs::write(day_path.join("src").join("lib.rs"), lib_rs)?;
 
         Ok(())
     }
 
+    #[test]
+    fn test_signatures() {
+        let source = "\
+pub fn part1(input: &str) -> (usize, Vec<u8>) {
+    let f = |x: u8| -> u8 { x };
+}
+pub fn part1_map(
+    input: &str,
+    seen: HashMap<u8, u8>,
+) -> (usize, Vec<u8>) where {
+}
+impl Grid {
+    pub fn part1_method(&self) -> usize {}
+}
+pub fn part1_braces(input: &str) -> usize {
+    let open = '{'; // }
+    let url = \"https://example.com/{\"; /* } /* nested */ { */
+    let raw = r#\"}\" {\"#;
+    input.find(open).unwrap_or(url.len() + raw.len())
+}
+pub fn part1_static(input: &'static str) -> usize {
+    let quote = '\\'';
+    input.matches(quote).count()
+}
+mod inner {
+    pub fn part1_nested(input: &str) -> usize { input.len() }
+}
+";
+        let found = signatures(source);
+        let names: Vec<&str> = found.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(
+            names,
+            ["part1", "part1_map", "part1_braces", "part1_static"]
+        );
+        assert_eq!(found[0].params, ["&str"]);
+        assert_eq!(found[0].output, "(usize, Vec<u8>)");
+        assert_eq!(found[1].name, "part1_map");
+        assert_eq!(found[1].params, ["&str", "HashMap<u8, u8>"]);
+        assert_eq!(found[1].output, "(usize, Vec<u8>)");
+        assert_eq!(found[3].params, ["&'static str"]);
+    }
+
     #[test]
     fn test_runner_creation() -> Result<()> {
         let tempdir = TempDir::new()?;
@@ -153,22 +673,70 @@ pub fn part2(input: &str) -> usize {
 
         // Create workspace Cargo.toml
         let workspace_toml = r#"[workspace]
-members = ["day*"]
+members = ["day*", "runner"]
 resolver = "2"
 "#;
         fs::write(workspace.path().join("Cargo.toml"), workspace_toml)?;
 
         // Initialize runner
-        let tempdir = TempDir::new_in(workspace.path())?;
-        let runner = Runner::new(tempdir);
-
-        // Test part 1
-        let result = runner.run_day(1, 1, "test input")?;
-        assert_eq!(result, 42);
+        let runner = Runner::new(workspace.path().join("runner"));
+
+        // Test part 1, whose debug printing does not get in the way of its answer
+        let (result, output) = runner.run_day(1, 1, "test input")?;
+        assert_eq!(result.answer, 42);
+        assert_eq!(output.stdout, "parsing test input\n");
+        assert_eq!(output.stderr, "found 42\n");
+        assert_eq!(
+            output.render(),
+            "Debug output (stdout):\nparsing test input\nDebug output (stderr):\nfound 42\n"
+        );
 
         // Test part 2
-        let result = runner.run_day(1, 2, "test input")?;
-        assert_eq!(result, 84);
+        let (result, output) = runner.run_day(1, 2, "test input")?;
+        assert_eq!(result.answer, 84);
+        assert!(output.render().is_empty());
+
+        // A named empty input is an edge case worth running
+        let empty = check::prepare(workspace.path(), 1, "edge-empty", "")?;
+        assert_eq!(runner.run_day(1, 2, &empty.input)?.0.answer, 84);
+
+        let (bench, _) = runner.bench(1, 2, "test input", 3)?;
+        assert_eq!(bench.answer, 84);
+        assert_eq!(bench.runtimes.len(), 3);
+        assert!(bench.runtimes.is_sorted());
+        assert!(bench.render().starts_with("84 over 3 runs: min "));
+
+        // Part 2 and its alternative run side by side
+        let mut functions = vec![String::from("part2")];
+        functions.extend(runner.variants(1, 2)?);
+        assert_eq!(functions, ["part2", "part2_fast", "part2_multiline"]);
+        let (results, _) = runner.run_functions(1, &functions, "test input")?;
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[2].answer, 84);
+
+        // A panic names the function and where it happened
+        let functions = [String::from("part1"), String::from("part1_broken")];
+        let error = runner
+            .run_functions(1, &functions, "test input")
+            .unwrap_err();
+        let failure = error.downcast_ref::<Failure>().unwrap();
+        assert_eq!(failure.function, "part1_broken");
+        assert_eq!(
+            failure.panic,
+            Some(Panic {
+                message: String::from("no answer for \"test input\""),
+                file: String::from("day01/src/lib.rs"),
+                line: 19,
+                column: 5,
+            })
+        );
+        assert!(failure.output.stdout.contains("parsing test input"));
+        let rendered = failure.render(workspace.path(), false);
+        assert!(
+            rendered.contains("> 19 |     panic!(\"no answer for {:?}\", input)"),
+            "{}",
+            rendered
+        );
 
         Ok(())
     }
@@ -187,16 +755,15 @@ resolver = "2"
         fs::write(workspace.path().join("Cargo.toml"), workspace_toml)?;
 
         // Initialize runner
-        let tempdir = TempDir::new_in(workspace.path())?;
-        let runner = Runner::new(tempdir);
+        let runner = Runner::new(workspace.path().join("runner"));
 
         // Test cargo check
         let check_output = runner.check_day(1);
         assert!(!check_output.contains("error"));
 
         // Test cargo test
-        let test_output = runner.test_day(1);
-        assert!(!test_output.contains("failed"));
+        let report = runner.test_day(1);
+        assert!(report.passed, "{}", report.output);
 
         Ok(())
     }
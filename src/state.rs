@@ -0,0 +1,189 @@
+// src/state.rs
+use crate::aoc;
+use crate::input;
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Directory holding per-day state, relative to the workspace root.
+pub const STATE_DIR: &str = ".aocr";
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PartState {
+    pub completed: bool,
+    /// Unix timestamp of when the website accepted the answer
+    pub solved_at: Option<u64>,
+    pub last_answer: Option<String>,
+    pub last_runtime_nanos: Option<u64>,
+    /// Expected answer for the example input, taken from the puzzle description
+    pub example_answer: Option<String>,
+    /// The answer the website accepted
+    pub answer: Option<String>,
+    /// Answers the website rejected, in the order they were submitted
+    pub wrong_answers: Vec<String>,
+    /// Latest answers on the other named inputs, by input name
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub inputs: BTreeMap<String, InputRun>,
+    /// Correct answers on other named inputs, e.g. a teammate's accepted answer
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub expected: BTreeMap<String, String>,
+}
+
+/// The latest answer and runtime of a part on one named input.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct InputRun {
+    pub answer: String,
+    pub runtime_nanos: u64,
+}
+
+impl PartState {
+    pub fn last_runtime(&self) -> Option<Duration> {
+        self.last_runtime_nanos.map(Duration::from_nanos)
+    }
+
+    /// The correct answer on a named input, if it is known: the accepted answer for your
+    /// own input, the answer from the puzzle description for the example.
+    pub fn known_answer(&self, input_name: &str) -> Option<&str> {
+        match input_name {
+            input::DEFAULT_NAME => self.answer.as_deref(),
+            input::EXAMPLE_NAME => self.example_answer.as_deref(),
+            name => self.expected.get(name).map(String::as_str),
+        }
+    }
+
+    /// Seconds from the puzzle unlocking to the part being solved.
+    pub fn time_to_star(&self, year: u16, day: u8) -> Option<u64> {
+        let solved_at = self.solved_at?;
+        Some(solved_at.saturating_sub(aoc::unlock_time(year, day)))
+    }
+}
+
+/// Everything aocr remembers about one day, stored in `.aocr/dayNN.toml`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DayState {
+    pub part1: PartState,
+    pub part2: PartState,
+    /// Whether the day's `cargo test` passed the last time it ran
+    pub tests_passed: Option<bool>,
+    /// Run and save the inputs exactly as given, without normalizing them
+    pub preserve_input: bool,
+}
+
+impl DayState {
+    /// Path of the state file for `day`, relative to the workspace root.
+    pub fn path(day: u8) -> PathBuf {
+        PathBuf::from(format!("{}/day{:02}.toml", STATE_DIR, day))
+    }
+
+    pub fn load(root: &Path, day: u8) -> Result<Self> {
+        let path = root.join(Self::path(day));
+        match fs::read_to_string(&path) {
+            Ok(contents) => {
+                toml::from_str(&contents).with_context(|| format!("Failed to parse {:?}", path))
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e).with_context(|| format!("Failed to read {:?}", path)),
+        }
+    }
+
+    pub fn save(&self, root: &Path, day: u8) -> Result<()> {
+        let path = root.join(Self::path(day));
+        fs::create_dir_all(root.join(STATE_DIR))?;
+        fs::write(&path, toml::to_string(self)?)
+            .with_context(|| format!("Failed to write {:?}", path))
+    }
+
+    pub fn part(&self, part: u8) -> &PartState {
+        if part == 1 {
+            &self.part1
+        } else {
+            &self.part2
+        }
+    }
+
+    pub fn part_mut(&mut self, part: u8) -> &mut PartState {
+        if part == 1 {
+            &mut self.part1
+        } else {
+            &mut self.part2
+        }
+    }
+}
+
+/// Remember the latest answer and runtime of a part on the named input. Only your own
+/// input's answer is the one `submit` sends.
+pub fn record_run(
+    root: &Path,
+    day: u8,
+    part: u8,
+    input_name: &str,
+    answer: usize,
+    runtime: Duration,
+) -> Result<()> {
+    let mut state = DayState::load(root, day)?;
+    let part_state = state.part_mut(part);
+    let runtime_nanos = runtime.as_nanos() as u64;
+    if input_name == input::DEFAULT_NAME {
+        part_state.last_answer = Some(answer.to_string());
+        part_state.last_runtime_nanos = Some(runtime_nanos);
+    } else {
+        part_state.inputs.insert(
+            input_name.to_string(),
+            InputRun {
+                answer: answer.to_string(),
+                runtime_nanos,
+            },
+        );
+    }
+    state.save(root, day)
+}
+
+/// Remember whether the day's tests passed.
+pub fn record_tests(root: &Path, day: u8, passed: bool) -> Result<()> {
+    let mut state = DayState::load(root, day)?;
+    state.tests_passed = Some(passed);
+    state.save(root, day)
+}
+
+/// Seconds since the Unix epoch.
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_state_round_trip() -> Result<()> {
+        let dir = TempDir::new()?;
+        assert_eq!(DayState::load(dir.path(), 7)?, DayState::default());
+
+        let mut state = DayState::default();
+        state.part_mut(2).completed = true;
+        state.part_mut(2).last_runtime_nanos = Some(2_300_000);
+        state.save(dir.path(), 7)?;
+
+        let loaded = DayState::load(dir.path(), 7)?;
+        assert_eq!(loaded, state);
+        assert!(dir.path().join(".aocr/day07.toml").exists());
+        assert_eq!(
+            loaded.part(2).last_runtime(),
+            Some(Duration::from_micros(2300))
+        );
+
+        state.part_mut(1).solved_at = Some(aoc::unlock_time(2024, 7) + 754);
+        assert_eq!(state.part(1).time_to_star(2024, 7), Some(754));
+        assert_eq!(state.part(2).time_to_star(2024, 7), None);
+        Ok(())
+    }
+}
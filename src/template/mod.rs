@@ -0,0 +1,269 @@
+// src/template/mod.rs
+use anyhow::{bail, Context, Result};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Template used when none is configured.
+pub const DEFAULT_TEMPLATE: &str = "minimal";
+
+/// A template bundled into the binary, as a list of `(relative path, contents)` pairs.
+pub struct Builtin {
+    pub name: &'static str,
+    pub description: &'static str,
+    files: &'static [(&'static str, &'static str)],
+}
+
+pub const BUILTINS: &[Builtin] = &[
+    Builtin {
+        name: "minimal",
+        description: "Empty part1/part2 functions with test stubs",
+        files: &[
+            (
+                "Cargo.toml",
+                include_str!("../../templates/minimal/Cargo.toml"),
+            ),
+            (
+                "src/lib.rs",
+                include_str!("../../templates/minimal/src/lib.rs"),
+            ),
+        ],
+    },
+    Builtin {
+        name: "parse",
+        description: "A parse function producing an Input type shared by both parts",
+        files: &[
+            (
+                "Cargo.toml",
+                include_str!("../../templates/parse/Cargo.toml"),
+            ),
+            (
+                "src/lib.rs",
+                include_str!("../../templates/parse/src/lib.rs"),
+            ),
+        ],
+    },
+    Builtin {
+        name: "nom",
+        description: "A nom parser skeleton",
+        files: &[
+            ("Cargo.toml", include_str!("../../templates/nom/Cargo.toml")),
+            ("src/lib.rs", include_str!("../../templates/nom/src/lib.rs")),
+        ],
+    },
+    Builtin {
+        name: "grid",
+        description: "A 2D grid type with neighbor helpers",
+        files: &[
+            (
+                "Cargo.toml",
+                include_str!("../../templates/grid/Cargo.toml"),
+            ),
+            (
+                "src/lib.rs",
+                include_str!("../../templates/grid/src/lib.rs"),
+            ),
+        ],
+    },
+];
+
+/// The manifest and root module of the shared `common` crate.
+pub const COMMON_FILES: &[(&str, &str)] = &[
+    (
+        "Cargo.toml",
+        include_str!("../../templates/common/Cargo.toml"),
+    ),
+    (
+        "src/lib.rs",
+        include_str!("../../templates/common/src/lib.rs"),
+    ),
+];
+
+/// A curated helper module that can be installed into the `common` crate.
+pub struct CommonModule {
+    pub name: &'static str,
+    pub description: &'static str,
+    pub source: &'static str,
+}
+
+pub const COMMON_MODULES: &[CommonModule] = &[
+    CommonModule {
+        name: "grid",
+        description: "2D grid with bounds-checked neighbors",
+        source: include_str!("../../templates/common/src/grid.rs"),
+    },
+    CommonModule {
+        name: "graph",
+        description: "BFS and Dijkstra over implicit graphs",
+        source: include_str!("../../templates/common/src/graph.rs"),
+    },
+    CommonModule {
+        name: "parse",
+        description: "Integer extraction from free-form text",
+        source: include_str!("../../templates/common/src/parse.rs"),
+    },
+    CommonModule {
+        name: "memo",
+        description: "Memoization for recursive functions",
+        source: include_str!("../../templates/common/src/memo.rs"),
+    },
+];
+
+/// Values substituted into `{{placeholder}}`s when rendering a template.
+pub struct Variables {
+    pub day: u8,
+    pub year: u16,
+}
+
+impl Variables {
+    pub fn crate_name(&self) -> String {
+        format!("day{:02}", self.day)
+    }
+
+    /// Replace every supported placeholder in `text`.
+    pub fn substitute(&self, text: &str) -> String {
+        text.replace("{{crate_name}}", &self.crate_name())
+            .replace("{{day_padded}}", &format!("{:02}", self.day))
+            .replace("{{day}}", &self.day.to_string())
+            .replace("{{year}}", &self.year.to_string())
+    }
+}
+
+pub enum Template {
+    Builtin(&'static Builtin),
+    /// A user-provided directory laid out like a day crate.
+    Directory(PathBuf),
+}
+
+impl Template {
+    /// Resolve a template by built-in name, falling back to a directory path.
+    pub fn resolve(spec: &str) -> Result<Self> {
+        if let Some(builtin) = BUILTINS.iter().find(|b| b.name == spec) {
+            return Ok(Self::Builtin(builtin));
+        }
+        let path = PathBuf::from(spec);
+        if !path.is_dir() {
+            let builtins: Vec<String> = BUILTINS
+                .iter()
+                .map(|b| format!("  {:<8} {}", b.name, b.description))
+                .collect();
+            bail!(
+                "Unknown template {:?}: expected a template directory or one of\n{}",
+                spec,
+                builtins.join("\n")
+            );
+        }
+        if !path.join("src/lib.rs").is_file() {
+            bail!("Template directory {:?} is missing src/lib.rs", path);
+        }
+        Ok(Self::Directory(path))
+    }
+
+    /// Render every file of the template, returning paths relative to the day crate.
+    pub fn render(&self, vars: &Variables) -> Result<Vec<(PathBuf, String)>> {
+        match self {
+            Self::Builtin(builtin) => Ok(builtin
+                .files
+                .iter()
+                .map(|(path, contents)| (PathBuf::from(path), vars.substitute(contents)))
+                .collect()),
+            Self::Directory(root) => {
+                let mut files = Vec::new();
+                collect_files(root, root, &mut files)?;
+                let mut rendered = Vec::new();
+                for relative in files {
+                    let contents = fs::read_to_string(root.join(&relative))
+                        .with_context(|| format!("Failed to read template file {:?}", relative))?;
+                    let path = PathBuf::from(vars.substitute(&relative.to_string_lossy()));
+                    rendered.push((path, vars.substitute(&contents)));
+                }
+                // A directory template may omit the manifest and reuse the default one
+                if !rendered
+                    .iter()
+                    .any(|(path, _)| path == Path::new("Cargo.toml"))
+                {
+                    let minimal = Self::Builtin(&BUILTINS[0]).render(vars)?;
+                    rendered.extend(
+                        minimal
+                            .into_iter()
+                            .filter(|(path, _)| path == Path::new("Cargo.toml")),
+                    );
+                }
+                Ok(rendered)
+            }
+        }
+    }
+}
+
+fn collect_files(root: &Path, dir: &Path, files: &mut Vec<PathBuf>) -> Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            collect_files(root, &path, files)?;
+        } else {
+            files.push(path.strip_prefix(root)?.to_path_buf());
+        }
+    }
+    files.sort();
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_builtin_templates_render() -> Result<()> {
+        let vars = Variables { day: 7, year: 2023 };
+        for builtin in BUILTINS {
+            let files = Template::resolve(builtin.name)?.render(&vars)?;
+            let (_, lib) = files
+                .iter()
+                .find(|(path, _)| path == Path::new("src/lib.rs"))
+                .expect("lib.rs missing");
+            assert!(lib.contains("Advent of Code 2023, Day 7"));
+            assert!(lib.contains("pub fn part1"));
+            assert!(lib.contains("pub fn part2"));
+            assert!(
+                !lib.contains("{{"),
+                "unrendered placeholder in {}",
+                builtin.name
+            );
+            let (_, manifest) = files
+                .iter()
+                .find(|(path, _)| path == Path::new("Cargo.toml"))
+                .expect("Cargo.toml missing");
+            assert!(manifest.contains("name = \"day07\""));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_directory_template() -> Result<()> {
+        let dir = TempDir::new()?;
+        fs::create_dir_all(dir.path().join("src"))?;
+        fs::write(
+            dir.path().join("src/lib.rs"),
+            "// {{crate_name}} {{day_padded}} {{day}} {{year}}\n",
+        )?;
+        fs::write(
+            dir.path().join("notes-{{day_padded}}.md"),
+            "# Day {{day}}\n",
+        )?;
+
+        let template = Template::resolve(dir.path().to_str().unwrap())?;
+        let files = template.render(&Variables { day: 3, year: 2024 })?;
+
+        assert!(files.contains(&(PathBuf::from("src/lib.rs"), "// day03 03 3 2024\n".into())));
+        assert!(files.contains(&(PathBuf::from("notes-03.md"), "# Day 3\n".into())));
+        assert!(files
+            .iter()
+            .any(|(path, _)| path == Path::new("Cargo.toml")));
+        Ok(())
+    }
+
+    #[test]
+    fn test_unknown_template() {
+        assert!(Template::resolve("definitely-not-a-template").is_err());
+    }
+}
@@ -1,11 +1,85 @@
 // src/tui/app.rs
+use super::editor::Editor;
+use super::keymap::{Keymap, Mode};
+use super::palette::Palette;
+use super::search::Search;
+use crate::aoc::{
+    self,
+    leaderboard::{self, Leaderboard},
+    puzzle::Puzzle,
+};
+use crate::config::Config;
+use crate::git;
+use crate::history;
+use crate::input::{self, inspect::InputStats};
+use crate::state::{DayState, PartState};
 use anyhow::Result;
-use std::{collections::HashMap, path::PathBuf};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 
 #[derive(Default)]
 pub struct Challenge {
-    pub completed: bool,
-    pub input: Option<String>,
+    /// What is persisted about the part in `.aocr/dayNN.toml`
+    pub state: PartState,
+    /// Whether the day's tests passed when they last ran
+    pub tests_passed: Option<bool>,
+    pub has_input: bool,
+}
+
+/// How far along a part is, from nothing done to solved.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    NoInput,
+    Input,
+    TestsFailing,
+    TestsPassing,
+    Answered,
+    WrongAnswer,
+    Solved,
+}
+
+impl Status {
+    pub const ALL: [Status; 7] = [
+        Status::NoInput,
+        Status::Input,
+        Status::TestsFailing,
+        Status::TestsPassing,
+        Status::Answered,
+        Status::WrongAnswer,
+        Status::Solved,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Status::NoInput => "no input",
+            Status::Input => "input",
+            Status::TestsFailing => "tests failing",
+            Status::TestsPassing => "tests passing",
+            Status::Answered => "answered",
+            Status::WrongAnswer => "wrong answer",
+            Status::Solved => "solved",
+        }
+    }
+}
+
+impl Challenge {
+    /// The most advanced status the part has reached.
+    pub fn status(&self) -> Status {
+        if self.state.completed {
+            Status::Solved
+        } else if !self.state.wrong_answers.is_empty() {
+            Status::WrongAnswer
+        } else if self.state.last_answer.is_some() {
+            Status::Answered
+        } else {
+            match self.tests_passed {
+                Some(true) => Status::TestsPassing,
+                Some(false) => Status::TestsFailing,
+                None if self.has_input => Status::Input,
+                None => Status::NoInput,
+            }
+        }
+    }
 }
 
 pub struct App {
@@ -13,38 +87,106 @@ pub struct App {
     pub selected_part: u8,
     pub watched_day: u8,
     pub watched_part: u8,
+    /// First visible line of the output pane
     pub scroll: u16,
+    /// Whether the output pane sticks to its newest line
+    pub follow: bool,
+    /// The output search while its prompt is open
+    pub search: Option<Search>,
+    /// First visible line of the input pane
+    pub input_scroll: u16,
     pub input_mode: bool,
     pub cursor_blink_state: bool,
     pub show_scroll_hint: bool, // To show scroll hint briefly when entering input mode
-    pub cursor_position: usize,
-    pub current_input: String,
+    /// The input being edited in input mode
+    pub editor: Editor,
+    /// Which named input `r` runs and `i` edits
+    pub input_name: String,
     pub cargo_output: String,
     pub days: HashMap<(u8, u8), Challenge>,
+    pub config: Config,
+    /// The pane shown next to the output, if any
+    pub side_pane: Option<SidePane>,
+    pub side_scroll: u16,
+    /// Statistics of the selected input for the inspector pane
+    pub inspection: Option<InputStats>,
+    /// Runs of the selected day for the history pane, oldest first
+    pub history: Vec<history::Entry>,
+    /// The puzzle shown in the puzzle pane and the day it belongs to
+    pub puzzle: Option<(u8, Puzzle)>,
+    pub leaderboard: Option<(Leaderboard, leaderboard::Source)>,
+    /// Show time to star and wrong attempts next to each day
+    pub show_stats: bool,
+    /// First day shown when the days grid is scrolled, counted from zero
+    pub grid_offset: u16,
+    pub keymap: Keymap,
+    pub show_help: bool,
+    pub palette: Option<Palette>,
 }
 
 impl App {
     pub fn new() -> Self {
-        let mut days = HashMap::new();
-        for day in 1..=25 {
-            for part in 1..=2 {
-                days.insert((day, part), Challenge::default());
-            }
-        }
-
-        Self {
-            days,
+        let mut errors = Vec::new();
+        let config = Config::load(Path::new(".")).unwrap_or_else(|e| {
+            errors.push(format!("Error: {:#}", e));
+            Config::default()
+        });
+        let keymap = Keymap::from_config(&config.keys).unwrap_or_else(|e| {
+            errors.push(format!("Error: {:#}", e));
+            Keymap::default()
+        });
+        let mut app = Self {
+            days: HashMap::new(),
             selected_day: 1,
             selected_part: 1,
-            cargo_output: String::new(),
+            cargo_output: errors.join("\n"),
             input_mode: false,
-            current_input: String::new(),
-            cursor_position: 0,
+            editor: Editor::default(),
+            input_name: input::DEFAULT_NAME.to_string(),
             cursor_blink_state: false,
             watched_day: 1,
             watched_part: 1,
             scroll: 0,
+            follow: true,
+            search: None,
+            input_scroll: 0,
             show_scroll_hint: false,
+            config,
+            side_pane: None,
+            side_scroll: 0,
+            inspection: None,
+            history: Vec::new(),
+            puzzle: None,
+            leaderboard: None,
+            show_stats: false,
+            grid_offset: 0,
+            keymap,
+            show_help: false,
+            palette: None,
+        };
+        for day in 1..=25 {
+            app.reload_state(day);
+        }
+        app
+    }
+
+    /// Re-read a day's state from disk after a command changed it.
+    pub fn reload_state(&mut self, day: u8) {
+        let state = DayState::load(Path::new("."), day).unwrap_or_default();
+        for part in 1..=2 {
+            let challenge = self.days.entry((day, part)).or_default();
+            challenge.state = state.part(part).clone();
+            challenge.tests_passed = state.tests_passed;
+            challenge.has_input = input::path(day, part).exists();
+        }
+    }
+
+    /// The key bindings currently in effect.
+    pub fn mode(&self) -> Mode {
+        if self.input_mode {
+            Mode::Input
+        } else {
+            Mode::Navigation
         }
     }
 
@@ -55,93 +197,225 @@ impl App {
 
     pub fn move_cursor(&mut self, direction: Direction) {
         match direction {
-            Direction::Up if self.selected_day > 1 => self.selected_day -= 1,
-            Direction::Down if self.selected_day < 25 => self.selected_day += 1,
-            Direction::Left if self.selected_part > 1 => self.selected_part -= 1,
-            Direction::Right if self.selected_part < 2 => self.selected_part += 1,
+            Direction::Up if self.selected_day > 1 => self.select_day(self.selected_day - 1),
+            Direction::Down if self.selected_day < 25 => self.select_day(self.selected_day + 1),
+            Direction::Left if self.selected_part > 1 => {
+                self.selected_part -= 1;
+                self.inspect_input();
+            }
+            Direction::Right if self.selected_part < 2 => {
+                self.selected_part += 1;
+                self.inspect_input();
+            }
             _ => {}
         }
     }
 
-    pub fn toggle_completion(&mut self) {
-        if let Some(challenge) = self.days.get_mut(&(self.selected_day, self.selected_part)) {
-            challenge.completed = !challenge.completed;
+    pub fn select_day(&mut self, day: u8) {
+        let previous = self.selected_day;
+        self.selected_day = day.clamp(1, 25);
+        if self.side_pane == Some(SidePane::Puzzle) && previous != self.selected_day {
+            // Only the cache is consulted here so browsing days never hits the network
+            self.side_scroll = 0;
+            self.puzzle = aoc::puzzle::load_cached(Path::new("."), self.selected_day)
+                .ok()
+                .flatten()
+                .map(|puzzle| (self.selected_day, puzzle));
         }
+        self.inspect_input();
+        self.load_history();
     }
 
-    fn get_input_path(day: u8, part: u8) -> PathBuf {
-        PathBuf::from(format!("inputs/day{:02}_part{}.txt", day, part))
+    /// Show or hide the puzzle pane, fetching the selected day's puzzle if it is not cached.
+    pub fn toggle_puzzle(&mut self) -> Result<()> {
+        if self.toggle_side_pane(SidePane::Puzzle) {
+            self.load_puzzle(false)?;
+        }
+        Ok(())
     }
 
-    pub fn load_input(&mut self) {
-        let input_path = App::get_input_path(self.selected_day, self.selected_part);
-        if let Ok(input) = std::fs::read_to_string(input_path) {
-            self.current_input = input.clone();
-            self.update_challenge_input(input)
+    /// Show or hide the configured private leaderboard, downloading it if the cache is stale.
+    pub fn toggle_leaderboard(&mut self) -> Result<()> {
+        if !self.toggle_side_pane(SidePane::Leaderboard) {
+            return Ok(());
         }
+        let Some(id) = self.config.aoc.leaderboard.clone() else {
+            anyhow::bail!(
+                "Set the id of your private leaderboard as `aoc.leaderboard` in aocr.toml"
+            );
+        };
+        let root = Path::new(".");
+        let client = aoc::Client::from_config(root, &self.config);
+        self.leaderboard = Some(leaderboard::load(root, &client, self.config.year, &id)?);
+        Ok(())
     }
 
-    fn update_challenge_input(&mut self, input: String) {
-        // Update in memory
-        if let Some(challenge) = self.days.get_mut(&(self.selected_day, self.selected_part)) {
-            challenge.input = Some(input);
+    pub fn toggle_inspector(&mut self) {
+        if self.toggle_side_pane(SidePane::Inspector) {
+            self.inspect_input();
         }
     }
 
-    pub fn set_input(&mut self, input: String) -> Result<()> {
-        // Save to file
-        let input_path = App::get_input_path(self.selected_day, self.selected_part);
-        std::fs::create_dir_all(input_path.parent().unwrap())?;
-        std::fs::write(&input_path, &input)?;
-        self.update_challenge_input(input);
-        Ok(())
+    pub fn toggle_history(&mut self) {
+        if self.toggle_side_pane(SidePane::History) {
+            self.load_history();
+        }
     }
 
-    pub fn get_selected_challenge(&self) -> Option<&Challenge> {
-        self.days.get(&(self.selected_day, self.selected_part))
+    /// Refresh the history pane after the selection changed or a part ran.
+    pub fn load_history(&mut self) {
+        if self.side_pane == Some(SidePane::History) {
+            self.history = history::load(Path::new("."), self.selected_day).unwrap_or_default();
+        }
     }
-    pub fn insert_char(&mut self, c: char) {
-        self.current_input.insert(self.cursor_position, c);
-        self.cursor_position += 1;
+
+    /// Refresh the inspector pane after the selection or the input changed.
+    fn inspect_input(&mut self) {
+        if self.side_pane == Some(SidePane::Inspector) {
+            self.inspection = InputStats::load(
+                Path::new("."),
+                self.selected_day,
+                self.selected_part,
+                &self.input_name,
+            );
+        }
     }
 
-    pub fn insert_newline(&mut self) {
-        self.current_input.insert(self.cursor_position, '\n');
-        self.cursor_position += 1;
+    /// Returns whether `pane` is shown after toggling it.
+    fn toggle_side_pane(&mut self, pane: SidePane) -> bool {
+        self.side_scroll = 0;
+        if self.side_pane == Some(pane) {
+            self.side_pane = None;
+            false
+        } else {
+            self.side_pane = Some(pane);
+            true
+        }
     }
 
-    pub fn delete_char(&mut self) {
-        if self.cursor_position > 0 {
-            self.cursor_position -= 1;
-            if self.cursor_position < self.current_input.len() {
-                self.current_input.remove(self.cursor_position);
+    /// Submit the latest answer of the selected part, committing it if it is correct.
+    pub fn submit(&mut self) -> Result<String> {
+        let (day, part) = (self.selected_day, self.selected_part);
+        let root = Path::new(".");
+        let Some(answer) = DayState::load(root, day)?.part(part).last_answer.clone() else {
+            anyhow::bail!("Press 'r' to compute an answer before submitting it");
+        };
+        let client = aoc::Client::from_config(root, &self.config);
+        let verdict = aoc::submit::submit(root, &client, self.config.year, day, part, &answer)?;
+        self.reload_state(day);
+
+        let mut message = format!("Submitted {}: {}", answer, verdict);
+        if verdict == aoc::submit::Verdict::Correct {
+            let runtime = self.days[&(day, part)].state.last_runtime();
+            if let Some(commit) = git::commit_solved(root, &self.config.git, day, part, runtime)? {
+                message.push_str(&format!("\nCommitted: {}", commit));
             }
         }
+        Ok(message)
     }
 
-    pub fn move_cursor_left(&mut self) {
-        if self.cursor_position > 0 {
-            self.cursor_position -= 1;
+    /// Save the examples of the selected day's cached puzzle as example inputs.
+    ///
+    /// Returns a summary of what was found.
+    pub fn save_examples(&mut self) -> Result<String> {
+        let root = Path::new(".");
+        let day = self.selected_day;
+        let Some(puzzle) = aoc::puzzle::load_cached(root, day)? else {
+            anyhow::bail!(
+                "Day {} has not been downloaded yet. Press 'P' to fetch it.",
+                day
+            );
+        };
+        let found = aoc::examples::extract(&puzzle);
+        if found.is_empty() {
+            anyhow::bail!("No example found in the description of day {}", day);
         }
+        aoc::examples::save(root, day, &found)?;
+        Ok(found
+            .iter()
+            .map(|example| {
+                format!(
+                    "Saved part {} example (answer: {})",
+                    example.part,
+                    example.answer.as_deref().unwrap_or("unknown")
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n"))
+    }
+
+    /// Load the selected day's puzzle, downloading it again if `refresh` is set.
+    pub fn load_puzzle(&mut self, refresh: bool) -> Result<()> {
+        let root = Path::new(".");
+        let day = self.selected_day;
+        self.puzzle = None;
+        let client = aoc::Client::from_config(root, &self.config);
+        let puzzle = aoc::puzzle::load(root, &client, self.config.year, day, refresh)?;
+        self.puzzle = Some((day, puzzle));
+        Ok(())
     }
 
-    pub fn move_cursor_right(&mut self) {
-        if self.cursor_position < self.current_input.len() {
-            self.cursor_position += 1;
+    /// Toggle whether the selected part is solved and persist it to the day's state.
+    ///
+    /// Returns the commit message if solving it created a commit.
+    pub fn toggle_completion(&mut self) -> Result<Option<String>> {
+        let (day, part) = (self.selected_day, self.selected_part);
+        let root = Path::new(".");
+        let mut day_state = DayState::load(root, day)?;
+        let part_state = day_state.part_mut(part);
+        let completed = !part_state.completed;
+        part_state.completed = completed;
+        let runtime = part_state.last_runtime();
+        day_state.save(root, day)?;
+        self.reload_state(day);
+
+        if completed {
+            git::commit_solved(root, &self.config.git, day, part, runtime)
+        } else {
+            Ok(None)
         }
     }
 
-    pub fn move_cursor_start(&mut self) {
-        self.cursor_position = 0;
+    /// Where the selected named input of the selected part is stored.
+    pub fn input_path(&self) -> PathBuf {
+        input::named_path(self.selected_day, self.selected_part, &self.input_name)
+    }
+
+    /// Switch to the next stored input of the selected part.
+    pub fn next_input(&mut self) {
+        let names = input::names(Path::new("."), self.selected_day, self.selected_part);
+        let next = names
+            .iter()
+            .position(|name| *name == self.input_name)
+            .map_or(0, |i| (i + 1) % names.len());
+        self.select_input(names[next].clone());
     }
 
-    pub fn move_cursor_end(&mut self) {
-        self.cursor_position = self.current_input.len();
+    /// Switch to the stored input `name` of the selected part.
+    pub fn select_input(&mut self, name: String) {
+        self.input_name = name;
+        self.inspect_input();
+    }
+
+    pub fn load_input(&mut self) {
+        self.editor = match std::fs::read_to_string(self.input_path()) {
+            Ok(input) => Editor::new(&input),
+            Err(_) => Editor::default(),
+        };
     }
 
-    pub fn insert_text(&mut self, text: &str) {
-        self.current_input.insert_str(self.cursor_position, text);
-        self.cursor_position += text.len();
+    /// Save the input of the selected part, normalized unless the day preserves it.
+    /// Returns what normalization changed.
+    pub fn set_input(&mut self, raw: String) -> Result<Vec<String>> {
+        let checked =
+            input::check::prepare(Path::new("."), self.selected_day, &self.input_name, &raw)?;
+        // Save to file
+        let input_path = self.input_path();
+        std::fs::create_dir_all(input_path.parent().unwrap())?;
+        std::fs::write(&input_path, &checked.input)?;
+        self.reload_state(self.selected_day);
+        self.inspect_input();
+        Ok(checked.warnings)
     }
 
     pub fn toggle_cursor_blink(&mut self) {
@@ -149,6 +423,7 @@ impl App {
     }
 
     pub fn scroll_up(&mut self) {
+        self.follow = false;
         if self.scroll > 0 {
             self.scroll -= 1;
         }
@@ -161,6 +436,7 @@ impl App {
     }
 
     pub fn page_up(&mut self) {
+        self.follow = false;
         if self.scroll >= 10 {
             self.scroll -= 10;
         } else {
@@ -171,6 +447,33 @@ impl App {
     pub fn page_down(&mut self) {
         self.scroll += 10;
     }
+
+    /// Scroll the output to a match of the search: the next or previous one when `step`
+    /// is set, otherwise the nearest one from where the output is now.
+    pub fn find_match(&mut self, forward: bool, step: bool) {
+        let Some(search) = self.search.as_mut() else {
+            return;
+        };
+        let from = match (search.current, step) {
+            (Some(line), true) if forward => line + 1,
+            (Some(line), true) => line.checked_sub(1).unwrap_or(usize::MAX),
+            (Some(line), false) => line,
+            (None, _) => self.scroll as usize,
+        };
+        if let Some(line) = search.find(&self.cargo_output, from, forward) {
+            self.follow = false;
+            // Keep a little context above the match
+            self.scroll = line.saturating_sub(2) as u16;
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SidePane {
+    Puzzle,
+    Leaderboard,
+    Inspector,
+    History,
 }
 
 pub enum Direction {
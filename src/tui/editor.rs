@@ -0,0 +1,429 @@
+// src/tui/editor.rs
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Most edits `undo` can go back.
+const UNDO_LIMIT: usize = 100;
+
+/// A place in the text. `col` is a byte offset into the line, always on a grapheme
+/// boundary.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Position {
+    pub line: usize,
+    pub col: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Edit {
+    Insert,
+    Delete,
+    Other,
+}
+
+#[derive(Debug, Clone)]
+struct Snapshot {
+    lines: Vec<String>,
+    cursor: Position,
+}
+
+/// Multi-line text editor behind the input pane.
+#[derive(Debug, Clone)]
+pub struct Editor {
+    lines: Vec<String>,
+    cursor: Position,
+    /// Where the selection started, if there is one
+    anchor: Option<Position>,
+    /// Column, in graphemes, that up and down try to keep
+    goal: Option<usize>,
+    undo: Vec<Snapshot>,
+    redo: Vec<Snapshot>,
+    /// The kind of the last edit, so typing a word undoes as one step
+    last_edit: Option<Edit>,
+}
+
+impl Default for Editor {
+    fn default() -> Self {
+        Self::new("")
+    }
+}
+
+impl Editor {
+    pub fn new(text: &str) -> Self {
+        Self {
+            lines: text.split('\n').map(String::from).collect(),
+            cursor: Position::default(),
+            anchor: None,
+            goal: None,
+            undo: Vec::new(),
+            redo: Vec::new(),
+            last_edit: None,
+        }
+    }
+
+    pub fn text(&self) -> String {
+        self.lines.join("\n")
+    }
+
+    pub fn lines(&self) -> &[String] {
+        &self.lines
+    }
+
+    pub fn cursor(&self) -> Position {
+        self.cursor
+    }
+
+    /// The cursor's column counted in graphemes.
+    pub fn cursor_column(&self) -> usize {
+        self.lines[self.cursor.line][..self.cursor.col]
+            .graphemes(true)
+            .count()
+    }
+
+    /// The selected range, start first.
+    pub fn selection(&self) -> Option<(Position, Position)> {
+        let anchor = self.anchor.filter(|&anchor| anchor != self.cursor)?;
+        Some((anchor.min(self.cursor), anchor.max(self.cursor)))
+    }
+
+    pub fn selected_text(&self) -> Option<String> {
+        let (start, end) = self.selection()?;
+        if start.line == end.line {
+            return Some(self.lines[start.line][start.col..end.col].to_string());
+        }
+        let mut text = self.lines[start.line][start.col..].to_string();
+        for line in &self.lines[start.line + 1..end.line] {
+            text.push('\n');
+            text.push_str(line);
+        }
+        text.push('\n');
+        text.push_str(&self.lines[end.line][..end.col]);
+        Some(text)
+    }
+
+    /// Remember the text before an edit. Consecutive edits of the same kind share one
+    /// undo step.
+    fn checkpoint(&mut self, edit: Edit) {
+        if edit == Edit::Other || self.last_edit != Some(edit) {
+            self.undo.push(Snapshot {
+                lines: self.lines.clone(),
+                cursor: self.cursor,
+            });
+            if self.undo.len() > UNDO_LIMIT {
+                self.undo.remove(0);
+            }
+        }
+        self.redo.clear();
+        self.last_edit = Some(edit);
+        self.goal = None;
+    }
+
+    fn restore(&mut self, from_undo: bool) {
+        let (from, to) = if from_undo {
+            (&mut self.undo, &mut self.redo)
+        } else {
+            (&mut self.redo, &mut self.undo)
+        };
+        let Some(snapshot) = from.pop() else {
+            return;
+        };
+        to.push(Snapshot {
+            lines: std::mem::replace(&mut self.lines, snapshot.lines),
+            cursor: self.cursor,
+        });
+        self.cursor = snapshot.cursor;
+        self.anchor = None;
+        self.goal = None;
+        self.last_edit = None;
+    }
+
+    pub fn undo(&mut self) {
+        self.restore(true);
+    }
+
+    pub fn redo(&mut self) {
+        self.restore(false);
+    }
+
+    /// Remove the selected text, returning whether there was any.
+    fn delete_selection(&mut self) -> bool {
+        let Some((start, end)) = self.selection() else {
+            return false;
+        };
+        let tail = self.lines[end.line][end.col..].to_string();
+        let line = &mut self.lines[start.line];
+        line.truncate(start.col);
+        line.push_str(&tail);
+        self.lines.drain(start.line + 1..=end.line);
+        self.cursor = start;
+        self.anchor = None;
+        true
+    }
+
+    pub fn insert_char(&mut self, c: char) {
+        let mut buffer = [0; 4];
+        self.insert(c.encode_utf8(&mut buffer), Edit::Insert);
+    }
+
+    /// Insert text at the cursor, replacing the selection. Pasting many lines splices
+    /// them in at once.
+    pub fn insert_text(&mut self, text: &str) {
+        self.insert(&text.replace("\r\n", "\n"), Edit::Other);
+    }
+
+    fn insert(&mut self, text: &str, edit: Edit) {
+        self.checkpoint(if self.selection().is_some() {
+            Edit::Other
+        } else {
+            edit
+        });
+        self.delete_selection();
+
+        let Position { line, col } = self.cursor;
+        let tail = self.lines[line].split_off(col);
+        let mut pieces = text.split('\n');
+        self.lines[line].push_str(pieces.next().unwrap_or_default());
+        let rest: Vec<String> = pieces.map(String::from).collect();
+        if rest.is_empty() {
+            self.cursor.col = self.lines[line].len();
+            self.lines[line].push_str(&tail);
+        } else {
+            self.cursor = Position {
+                line: line + rest.len(),
+                col: rest[rest.len() - 1].len(),
+            };
+            self.lines.splice(line + 1..line + 1, rest);
+            self.lines[self.cursor.line].push_str(&tail);
+        }
+    }
+
+    pub fn insert_newline(&mut self) {
+        self.insert("\n", Edit::Other);
+    }
+
+    /// Delete the selection or the grapheme before the cursor.
+    pub fn delete_back(&mut self) {
+        if self.selection().is_none() && self.cursor == Position::default() {
+            return;
+        }
+        self.checkpoint(Edit::Delete);
+        if !self.delete_selection() {
+            self.anchor = Some(self.cursor);
+            self.cursor = self.previous(self.cursor);
+            self.delete_selection();
+        }
+    }
+
+    /// Delete the selection or the grapheme after the cursor.
+    pub fn delete_forward(&mut self) {
+        if self.selection().is_none() && self.next(self.cursor) == self.cursor {
+            return;
+        }
+        self.checkpoint(Edit::Delete);
+        if !self.delete_selection() {
+            self.anchor = Some(self.next(self.cursor));
+            self.delete_selection();
+        }
+    }
+
+    /// Remove the selection and return it, for cutting to the clipboard.
+    pub fn cut(&mut self) -> Option<String> {
+        let text = self.selected_text()?;
+        self.checkpoint(Edit::Other);
+        self.delete_selection();
+        Some(text)
+    }
+
+    /// The position one grapheme back, moving to the end of the previous line.
+    fn previous(&self, position: Position) -> Position {
+        let line = &self.lines[position.line];
+        match line[..position.col].grapheme_indices(true).next_back() {
+            Some((col, _)) => Position { col, ..position },
+            None if position.line > 0 => Position {
+                line: position.line - 1,
+                col: self.lines[position.line - 1].len(),
+            },
+            None => position,
+        }
+    }
+
+    /// The position one grapheme on, moving to the start of the next line.
+    fn next(&self, position: Position) -> Position {
+        let line = &self.lines[position.line];
+        match line[position.col..].graphemes(true).next() {
+            Some(grapheme) => Position {
+                col: position.col + grapheme.len(),
+                ..position
+            },
+            None if position.line + 1 < self.lines.len() => Position {
+                line: position.line + 1,
+                col: 0,
+            },
+            None => position,
+        }
+    }
+
+    /// Move the cursor, extending the selection when `select` is set.
+    fn move_to(&mut self, position: Position, select: bool) {
+        if select {
+            self.anchor.get_or_insert(self.cursor);
+        } else {
+            self.anchor = None;
+        }
+        self.cursor = position;
+        self.last_edit = None;
+    }
+
+    pub fn move_left(&mut self, select: bool) {
+        let position = match (select, self.selection()) {
+            (false, Some((start, _))) => start,
+            _ => self.previous(self.cursor),
+        };
+        self.goal = None;
+        self.move_to(position, select);
+    }
+
+    pub fn move_right(&mut self, select: bool) {
+        let position = match (select, self.selection()) {
+            (false, Some((_, end))) => end,
+            _ => self.next(self.cursor),
+        };
+        self.goal = None;
+        self.move_to(position, select);
+    }
+
+    /// Move up or down `lines` lines, keeping the column where the move started.
+    pub fn move_lines(&mut self, lines: isize, select: bool) {
+        let goal = *self.goal.get_or_insert(self.cursor_column());
+        let line = self
+            .cursor
+            .line
+            .saturating_add_signed(lines)
+            .min(self.lines.len() - 1);
+        let col = self.lines[line]
+            .grapheme_indices(true)
+            .nth(goal)
+            .map_or(self.lines[line].len(), |(col, _)| col);
+        self.move_to(Position { line, col }, select);
+    }
+
+    pub fn move_word_left(&mut self, select: bool) {
+        let mut position = self.previous(self.cursor);
+        // Skip the gap before the word, then the word itself
+        while position.col > 0 && !self.is_word_before(position) {
+            position = self.previous(position);
+        }
+        while self.is_word_before(position) {
+            position = self.previous(position);
+        }
+        self.goal = None;
+        self.move_to(position, select);
+    }
+
+    pub fn move_word_right(&mut self, select: bool) {
+        let mut position = self.next(self.cursor);
+        // Skip the gap after the cursor, then the rest of the word
+        while position.col < self.lines[position.line].len() && !self.is_word_after(position) {
+            position = self.next(position);
+        }
+        while self.is_word_after(position) {
+            position = self.next(position);
+        }
+        self.goal = None;
+        self.move_to(position, select);
+    }
+
+    fn is_word_before(&self, position: Position) -> bool {
+        self.lines[position.line][..position.col]
+            .chars()
+            .next_back()
+            .is_some_and(|c| c.is_alphanumeric() || c == '_')
+    }
+
+    fn is_word_after(&self, position: Position) -> bool {
+        self.lines[position.line][position.col..]
+            .chars()
+            .next()
+            .is_some_and(|c| c.is_alphanumeric() || c == '_')
+    }
+
+    pub fn move_line_start(&mut self) {
+        self.goal = None;
+        self.move_to(
+            Position {
+                col: 0,
+                ..self.cursor
+            },
+            false,
+        );
+    }
+
+    pub fn move_line_end(&mut self) {
+        let col = self.lines[self.cursor.line].len();
+        self.goal = None;
+        self.move_to(Position { col, ..self.cursor }, false);
+    }
+
+    pub fn move_document_start(&mut self) {
+        self.goal = None;
+        self.move_to(Position::default(), false);
+    }
+
+    pub fn move_document_end(&mut self) {
+        let line = self.lines.len() - 1;
+        let col = self.lines[line].len();
+        self.goal = None;
+        self.move_to(Position { line, col }, false);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_editing_multibyte_text() {
+        let mut editor = Editor::new("héllo\nwörld");
+        editor.move_right(false);
+        editor.move_right(false);
+        editor.delete_back();
+        editor.insert_char('ä');
+        assert_eq!(editor.text(), "hällo\nwörld");
+
+        // Up and down keep the column, even across a shorter line
+        editor.move_document_end();
+        editor.insert_text("\nab");
+        editor.move_lines(-1, false);
+        // "wö" is three bytes
+        assert_eq!(editor.cursor(), Position { line: 1, col: 3 });
+        editor.move_lines(-1, false);
+        editor.move_lines(1, false);
+        editor.move_lines(1, false);
+        assert_eq!(editor.cursor(), Position { line: 2, col: 2 });
+
+        editor.move_document_start();
+        editor.move_word_right(false);
+        editor.delete_forward();
+        assert_eq!(editor.text(), "hällowörld\nab");
+    }
+
+    #[test]
+    fn test_selection_and_undo() {
+        let mut editor = Editor::new("one two\nthree");
+        editor.move_word_right(false);
+        editor.move_lines(1, true);
+        assert_eq!(editor.selected_text().as_deref(), Some(" two\nthr"));
+
+        editor.insert_text("\n1\n2\n");
+        assert_eq!(editor.text(), "one\n1\n2\nee");
+        editor.insert_char('x');
+        editor.insert_char('y');
+        assert_eq!(editor.text(), "one\n1\n2\nxyee");
+
+        // Typing undoes as one step, then the paste
+        editor.undo();
+        assert_eq!(editor.text(), "one\n1\n2\nee");
+        editor.undo();
+        assert_eq!(editor.text(), "one two\nthree");
+        editor.redo();
+        assert_eq!(editor.text(), "one\n1\n2\nee");
+    }
+}
@@ -0,0 +1,120 @@
+// src/tui/external_editor.rs
+use std::env;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// A file to open, optionally at a line and column.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Location {
+    pub path: PathBuf,
+    pub line: Option<u32>,
+    pub column: Option<u32>,
+}
+
+impl Location {
+    /// The day's `lib.rs`.
+    pub fn lib(day: u8) -> Self {
+        Self {
+            path: PathBuf::from(format!("day{:02}/src/lib.rs", day)),
+            line: None,
+            column: None,
+        }
+    }
+}
+
+/// The first ` --> dayNN/src/...:line:col` location cargo reported for `day`.
+pub fn first_diagnostic(output: &str, day: u8) -> Option<Location> {
+    let prefix = format!("day{:02}/", day);
+    output.lines().find_map(|line| {
+        let location = line.trim_start().strip_prefix("--> ")?.trim();
+        if !location.starts_with(&prefix) {
+            return None;
+        }
+        let mut parts = location.rsplitn(3, ':');
+        let column = parts.next()?.parse().ok()?;
+        let line = parts.next()?.parse().ok()?;
+        Some(Location {
+            path: PathBuf::from(parts.next()?),
+            line: Some(line),
+            column: Some(column),
+        })
+    })
+}
+
+/// The editor from `$VISUAL` or `$EDITOR`, which may include arguments such as
+/// `code --wait`.
+pub fn configured() -> Option<String> {
+    ["VISUAL", "EDITOR"]
+        .into_iter()
+        .filter_map(|name| env::var(name).ok())
+        .find(|editor| !editor.trim().is_empty())
+}
+
+/// The command opening `location` in `editor`. Most terminal editors take `+line`;
+/// the ones that do not take `path:line:col` instead.
+pub fn command(editor: &str, location: &Location) -> Command {
+    let mut words = editor.split_whitespace();
+    let program = words.next().unwrap_or(editor);
+    let mut command = Command::new(program);
+    command.args(words);
+
+    let name = Path::new(program)
+        .file_stem()
+        .and_then(|name| name.to_str())
+        .unwrap_or(program);
+    let Some(line) = location.line else {
+        command.arg(&location.path);
+        return command;
+    };
+    let position = format!(
+        "{}:{}:{}",
+        location.path.display(),
+        line,
+        location.column.unwrap_or(1)
+    );
+    match name {
+        "code" | "codium" => command.arg("--goto").arg(position),
+        "subl" | "zed" | "hx" | "helix" => command.arg(position),
+        _ => command.arg(format!("+{}", line)).arg(&location.path),
+    };
+    command
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_open_diagnostic() {
+        let output = "\
+warning: unused variable: `input`
+ --> day01/src/lib.rs:3:14
+  |
+error[E0308]: mismatched types
+ --> day03/src/grid.rs:12:5
+";
+        let location = first_diagnostic(output, 3).unwrap();
+        assert_eq!(
+            location,
+            Location {
+                path: PathBuf::from("day03/src/grid.rs"),
+                line: Some(12),
+                column: Some(5),
+            }
+        );
+        assert_eq!(first_diagnostic(output, 2), None);
+
+        let args = |editor, location| -> Vec<String> {
+            command(editor, location)
+                .get_args()
+                .map(|arg| arg.to_string_lossy().into_owned())
+                .collect()
+        };
+        assert_eq!(args("vim", &location), ["+12", "day03/src/grid.rs"]);
+        assert_eq!(
+            args("code --wait", &location),
+            ["--wait", "--goto", "day03/src/grid.rs:12:5"]
+        );
+        assert_eq!(args("nano", &Location::lib(3)), ["day03/src/lib.rs"]);
+    }
+}
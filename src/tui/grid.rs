@@ -0,0 +1,104 @@
+// src/tui/grid.rs
+
+/// Number of days in the calendar.
+pub const DAYS: u16 = 25;
+
+/// Most day columns the compact layout uses before falling back to scrolling.
+const MAX_COLUMNS: u16 = 4;
+
+/// How the days grid fits into the height it is given.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GridLayout {
+    /// One day per row, all of them visible
+    Full,
+    /// Days spread over several columns of `rows` days each, without runtimes
+    Compact { columns: u16, rows: u16 },
+    /// One day per row, `rows` of them visible at a time
+    Scroll { rows: u16 },
+}
+
+impl GridLayout {
+    /// Pick a layout for a grid whose rows, inside its borders, are `height` tall.
+    pub fn fit(height: u16) -> Self {
+        if height >= DAYS {
+            return GridLayout::Full;
+        }
+        let columns = DAYS.div_ceil(height.max(1));
+        if columns <= MAX_COLUMNS {
+            GridLayout::Compact {
+                columns,
+                rows: DAYS.div_ceil(columns),
+            }
+        } else {
+            GridLayout::Scroll {
+                rows: height.max(1),
+            }
+        }
+    }
+
+    /// Width of the grid including its borders, given the width of a full row.
+    pub fn width(self, full_row: u16) -> u16 {
+        match self {
+            // "DD 1 2" per column, separated by two spaces, plus the borders
+            GridLayout::Compact { columns, .. } => columns * 8,
+            _ => full_row,
+        }
+    }
+}
+
+/// The first visible row once `selected` is scrolled into a window of `visible` rows,
+/// moving the window as little as possible from `offset`.
+pub fn scroll_offset(selected: u16, offset: u16, visible: u16) -> u16 {
+    let visible = visible.max(1);
+    let offset = if selected < offset {
+        selected
+    } else if selected >= offset + visible {
+        selected + 1 - visible
+    } else {
+        offset
+    };
+    offset.min(DAYS.saturating_sub(visible))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_grid_layout() {
+        assert_eq!(GridLayout::fit(30), GridLayout::Full);
+        assert_eq!(
+            GridLayout::fit(20),
+            GridLayout::Compact {
+                columns: 2,
+                rows: 13
+            }
+        );
+        assert_eq!(
+            GridLayout::fit(7),
+            GridLayout::Compact {
+                columns: 4,
+                rows: 7
+            }
+        );
+        assert_eq!(GridLayout::fit(5), GridLayout::Scroll { rows: 5 });
+        assert_eq!(
+            GridLayout::Compact {
+                columns: 2,
+                rows: 13
+            }
+            .width(27),
+            16
+        );
+    }
+
+    #[test]
+    fn test_scroll_offset() {
+        assert_eq!(scroll_offset(0, 0, 5), 0);
+        assert_eq!(scroll_offset(7, 0, 5), 3);
+        assert_eq!(scroll_offset(5, 3, 5), 3);
+        assert_eq!(scroll_offset(1, 3, 5), 1);
+        // A taller window never scrolls past the last day
+        assert_eq!(scroll_offset(24, 22, 10), 15);
+    }
+}
@@ -0,0 +1,666 @@
+// src/tui/keymap.rs
+use crate::config::KeysConfig;
+use anyhow::{anyhow, bail, Context, Result};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
+
+/// Everything a key can do in `aocr watch`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    Quit,
+    Help,
+    Palette,
+    Left,
+    Right,
+    Up,
+    Down,
+    PageUp,
+    PageDown,
+    ScrollUp,
+    ScrollDown,
+    ToggleFollow,
+    Search,
+    Watch,
+    Test,
+    Run,
+    Bench,
+    EditInput,
+    NextInput,
+    OpenEditor,
+    ToggleSolved,
+    Submit,
+    TogglePuzzle,
+    FetchPuzzle,
+    ToggleLeaderboard,
+    ToggleInspector,
+    ToggleHistory,
+    SaveExamples,
+    ToggleStats,
+    SideScrollUp,
+    SideScrollDown,
+    SaveInput,
+    Paste,
+    Newline,
+    CursorLeft,
+    CursorRight,
+    LineStart,
+    LineEnd,
+    CursorUp,
+    CursorDown,
+    WordLeft,
+    WordRight,
+    DocumentStart,
+    DocumentEnd,
+    SelectLeft,
+    SelectRight,
+    SelectUp,
+    SelectDown,
+    DeleteBack,
+    DeleteForward,
+    Copy,
+    Cut,
+    Undo,
+    Redo,
+    CancelInput,
+}
+
+impl Action {
+    pub const ALL: [Action; 55] = [
+        Action::Quit,
+        Action::Help,
+        Action::Palette,
+        Action::Left,
+        Action::Right,
+        Action::Up,
+        Action::Down,
+        Action::PageUp,
+        Action::PageDown,
+        Action::ScrollUp,
+        Action::ScrollDown,
+        Action::ToggleFollow,
+        Action::Search,
+        Action::Watch,
+        Action::Test,
+        Action::Run,
+        Action::Bench,
+        Action::EditInput,
+        Action::NextInput,
+        Action::OpenEditor,
+        Action::ToggleSolved,
+        Action::Submit,
+        Action::TogglePuzzle,
+        Action::FetchPuzzle,
+        Action::ToggleLeaderboard,
+        Action::ToggleInspector,
+        Action::ToggleHistory,
+        Action::SaveExamples,
+        Action::ToggleStats,
+        Action::SideScrollUp,
+        Action::SideScrollDown,
+        Action::SaveInput,
+        Action::Paste,
+        Action::Newline,
+        Action::CursorLeft,
+        Action::CursorRight,
+        Action::LineStart,
+        Action::LineEnd,
+        Action::CursorUp,
+        Action::CursorDown,
+        Action::WordLeft,
+        Action::WordRight,
+        Action::DocumentStart,
+        Action::DocumentEnd,
+        Action::SelectLeft,
+        Action::SelectRight,
+        Action::SelectUp,
+        Action::SelectDown,
+        Action::DeleteBack,
+        Action::DeleteForward,
+        Action::Copy,
+        Action::Cut,
+        Action::Undo,
+        Action::Redo,
+        Action::CancelInput,
+    ];
+
+    /// The action with the given name, as used in `[keys]` of `aocr.toml`.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|action| action.name() == name)
+    }
+
+    /// Short name used by the command palette and the `[keys]` config.
+    pub fn name(self) -> &'static str {
+        match self {
+            Action::Quit => "quit",
+            Action::Help => "help",
+            Action::Palette => "palette",
+            Action::Left => "left",
+            Action::Right => "right",
+            Action::Up => "up",
+            Action::Down => "down",
+            Action::PageUp => "page-up",
+            Action::PageDown => "page-down",
+            Action::ScrollUp => "scroll-up",
+            Action::ScrollDown => "scroll-down",
+            Action::ToggleFollow => "follow",
+            Action::Search => "search",
+            Action::Watch => "watch",
+            Action::Test => "test",
+            Action::Run => "run",
+            Action::Bench => "bench",
+            Action::EditInput => "edit-input",
+            Action::NextInput => "next-input",
+            Action::OpenEditor => "open-editor",
+            Action::ToggleSolved => "toggle-solved",
+            Action::Submit => "submit",
+            Action::TogglePuzzle => "puzzle",
+            Action::FetchPuzzle => "fetch-puzzle",
+            Action::ToggleLeaderboard => "leaderboard",
+            Action::ToggleInspector => "inspect",
+            Action::ToggleHistory => "history",
+            Action::SaveExamples => "save-examples",
+            Action::ToggleStats => "stats",
+            Action::SideScrollUp => "side-scroll-up",
+            Action::SideScrollDown => "side-scroll-down",
+            Action::SaveInput => "save-input",
+            Action::Paste => "paste",
+            Action::Newline => "newline",
+            Action::CursorLeft => "cursor-left",
+            Action::CursorRight => "cursor-right",
+            Action::LineStart => "line-start",
+            Action::LineEnd => "line-end",
+            Action::CursorUp => "cursor-up",
+            Action::CursorDown => "cursor-down",
+            Action::WordLeft => "word-left",
+            Action::WordRight => "word-right",
+            Action::DocumentStart => "document-start",
+            Action::DocumentEnd => "document-end",
+            Action::SelectLeft => "select-left",
+            Action::SelectRight => "select-right",
+            Action::SelectUp => "select-up",
+            Action::SelectDown => "select-down",
+            Action::DeleteForward => "delete-forward",
+            Action::Copy => "copy",
+            Action::Cut => "cut",
+            Action::Undo => "undo",
+            Action::Redo => "redo",
+            Action::DeleteBack => "delete-back",
+            Action::CancelInput => "cancel-input",
+        }
+    }
+
+    pub fn description(self) -> &'static str {
+        match self {
+            Action::Quit => "Quit",
+            Action::Help => "Show the key bindings",
+            Action::Palette => "Open the command palette",
+            Action::Left => "Select part 1",
+            Action::Right => "Select part 2",
+            Action::Up => "Select the previous day",
+            Action::Down => "Select the next day",
+            Action::PageUp => "Scroll up a page",
+            Action::PageDown => "Scroll down a page",
+            Action::ScrollUp => "Scroll up a line",
+            Action::ScrollDown => "Scroll down a line",
+            Action::ToggleFollow => "Keep the output scrolled to its newest line, or stop",
+            Action::Search => "Search the output",
+            Action::Watch => "Watch the selected day and run cargo check",
+            Action::Test => "Run cargo test for the watched day",
+            Action::Run => "Run the selected part on its input",
+            Action::Bench => "Time the selected part over several runs",
+            Action::EditInput => "Edit the input of the selected part",
+            Action::NextInput => "Switch to the next named input of the selected part",
+            Action::OpenEditor => "Open the day's code, or the first error, in $VISUAL or $EDITOR",
+            Action::ToggleSolved => "Mark the selected part solved or unsolved",
+            Action::Submit => "Submit the latest answer of the selected part",
+            Action::TogglePuzzle => "Show or hide the puzzle description",
+            Action::FetchPuzzle => "Download the puzzle description again",
+            Action::ToggleLeaderboard => "Show or hide the private leaderboard",
+            Action::ToggleInspector => "Show or hide statistics of the selected input",
+            Action::ToggleHistory => "Show or hide the runs of the selected day",
+            Action::SaveExamples => "Save the examples from the puzzle description",
+            Action::ToggleStats => "Show or hide time to star and wrong answers",
+            Action::SideScrollUp => "Scroll the side pane up",
+            Action::SideScrollDown => "Scroll the side pane down",
+            Action::SaveInput => "Save the input and leave the editor",
+            Action::Paste => "Paste from the clipboard",
+            Action::Newline => "Insert a newline",
+            Action::CursorLeft => "Move the cursor left",
+            Action::CursorRight => "Move the cursor right",
+            Action::LineStart => "Move the cursor to the start of the line",
+            Action::LineEnd => "Move the cursor to the end of the line",
+            Action::CursorUp => "Move the cursor up a line",
+            Action::CursorDown => "Move the cursor down a line",
+            Action::WordLeft => "Move the cursor to the previous word",
+            Action::WordRight => "Move the cursor to the next word",
+            Action::DocumentStart => "Move the cursor to the start of the input",
+            Action::DocumentEnd => "Move the cursor to the end of the input",
+            Action::SelectLeft => "Extend the selection left",
+            Action::SelectRight => "Extend the selection right",
+            Action::SelectUp => "Extend the selection up a line",
+            Action::SelectDown => "Extend the selection down a line",
+            Action::DeleteForward => "Delete the character after the cursor",
+            Action::Copy => "Copy the selection to the clipboard",
+            Action::Cut => "Cut the selection to the clipboard",
+            Action::Undo => "Undo the last edit",
+            Action::Redo => "Redo the last undone edit",
+            Action::DeleteBack => "Delete the character before the cursor",
+            Action::CancelInput => "Leave the editor without saving",
+        }
+    }
+
+    /// Whether the command palette offers the action. Movement is left to the keys, and
+    /// editing to the input pane.
+    pub fn in_palette(self) -> bool {
+        !matches!(
+            self,
+            Action::Palette
+                | Action::Left
+                | Action::Right
+                | Action::Up
+                | Action::Down
+                | Action::PageUp
+                | Action::PageDown
+                | Action::ScrollUp
+                | Action::ScrollDown
+                | Action::SideScrollUp
+                | Action::SideScrollDown
+        ) && !self.edits_input()
+    }
+
+    /// Whether the action only makes sense while editing the input.
+    fn edits_input(self) -> bool {
+        matches!(
+            self,
+            Action::SaveInput
+                | Action::Paste
+                | Action::Newline
+                | Action::CursorLeft
+                | Action::CursorRight
+                | Action::CursorUp
+                | Action::CursorDown
+                | Action::WordLeft
+                | Action::WordRight
+                | Action::LineStart
+                | Action::LineEnd
+                | Action::DocumentStart
+                | Action::DocumentEnd
+                | Action::SelectLeft
+                | Action::SelectRight
+                | Action::SelectUp
+                | Action::SelectDown
+                | Action::DeleteBack
+                | Action::DeleteForward
+                | Action::Copy
+                | Action::Cut
+                | Action::Undo
+                | Action::Redo
+                | Action::CancelInput
+        )
+    }
+}
+
+/// Which set of bindings is active.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    Navigation,
+    Input,
+}
+
+/// A key together with the modifiers that must be held.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KeyBinding {
+    pub code: KeyCode,
+    pub modifiers: KeyModifiers,
+}
+
+impl KeyBinding {
+    pub const fn new(code: KeyCode, modifiers: KeyModifiers) -> Self {
+        Self { code, modifiers }
+    }
+
+    const fn key(code: KeyCode) -> Self {
+        Self::new(code, KeyModifiers::NONE)
+    }
+
+    const fn char(c: char) -> Self {
+        Self::key(KeyCode::Char(c))
+    }
+
+    const fn ctrl(code: KeyCode) -> Self {
+        Self::new(code, KeyModifiers::CONTROL)
+    }
+
+    const fn shift(code: KeyCode) -> Self {
+        Self::new(code, KeyModifiers::SHIFT)
+    }
+
+    /// The binding a key press triggers. Shift is part of the character for letters
+    /// and symbols, so it is dropped there.
+    pub fn from_event(event: &KeyEvent) -> Self {
+        let mut modifiers = event.modifiers;
+        if matches!(event.code, KeyCode::Char(_)) {
+            modifiers.remove(KeyModifiers::SHIFT);
+        }
+        Self::new(event.code, modifiers)
+    }
+}
+
+/// Parse keys such as `"q"`, `"ctrl+s"`, `"PageUp"` or `"shift+tab"`. Modifier and key
+/// names ignore case; single characters are taken as they are.
+impl FromStr for KeyBinding {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        // A trailing "+" is the key itself, as in "ctrl++"
+        let (modifiers, key) = match s.strip_suffix("++") {
+            Some(modifiers) => (Some(modifiers), "+"),
+            None => match s.rsplit_once('+') {
+                Some((modifiers, key)) if !key.is_empty() => (Some(modifiers), key),
+                _ => (None, s),
+            },
+        };
+
+        let mut binding = KeyBinding::key(KeyCode::Null);
+        for modifier in modifiers.into_iter().flat_map(|m| m.split('+')) {
+            binding.modifiers |= match modifier.to_lowercase().as_str() {
+                "ctrl" | "control" => KeyModifiers::CONTROL,
+                "alt" => KeyModifiers::ALT,
+                "shift" => KeyModifiers::SHIFT,
+                _ => bail!("Unknown modifier {:?} in key {:?}", modifier, s),
+            };
+        }
+
+        let mut chars = key.chars();
+        binding.code = match (chars.next(), chars.next()) {
+            (Some(c), None) => KeyCode::Char(c),
+            _ => match key.to_lowercase().as_str() {
+                "space" => KeyCode::Char(' '),
+                "enter" | "return" => KeyCode::Enter,
+                "esc" | "escape" => KeyCode::Esc,
+                "backspace" => KeyCode::Backspace,
+                "tab" => KeyCode::Tab,
+                "delete" | "del" => KeyCode::Delete,
+                "left" => KeyCode::Left,
+                "right" => KeyCode::Right,
+                "up" => KeyCode::Up,
+                "down" => KeyCode::Down,
+                "home" => KeyCode::Home,
+                "end" => KeyCode::End,
+                "pageup" => KeyCode::PageUp,
+                "pagedown" => KeyCode::PageDown,
+                name => name
+                    .strip_prefix('f')
+                    .and_then(|n| n.parse().ok())
+                    .map(KeyCode::F)
+                    .ok_or_else(|| anyhow!("Unknown key {:?}", s))?,
+            },
+        };
+
+        // Shift is part of the character, matching `from_event`
+        if let KeyCode::Char(c) = binding.code {
+            if binding.modifiers.contains(KeyModifiers::SHIFT) {
+                binding.modifiers.remove(KeyModifiers::SHIFT);
+                binding.code = KeyCode::Char(c.to_ascii_uppercase());
+            }
+        }
+        Ok(binding)
+    }
+}
+
+impl fmt::Display for KeyBinding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.modifiers.contains(KeyModifiers::CONTROL) {
+            write!(f, "Ctrl+")?;
+        }
+        if self.modifiers.contains(KeyModifiers::ALT) {
+            write!(f, "Alt+")?;
+        }
+        if self.modifiers.contains(KeyModifiers::SHIFT) {
+            write!(f, "Shift+")?;
+        }
+        match self.code {
+            KeyCode::Char(' ') => write!(f, "Space"),
+            KeyCode::Char(c) => write!(f, "{}", c),
+            KeyCode::F(n) => write!(f, "F{}", n),
+            KeyCode::Backspace => write!(f, "Backspace"),
+            KeyCode::Enter => write!(f, "Enter"),
+            KeyCode::Left => write!(f, "Left"),
+            KeyCode::Right => write!(f, "Right"),
+            KeyCode::Up => write!(f, "Up"),
+            KeyCode::Down => write!(f, "Down"),
+            KeyCode::Home => write!(f, "Home"),
+            KeyCode::End => write!(f, "End"),
+            KeyCode::PageUp => write!(f, "PageUp"),
+            KeyCode::PageDown => write!(f, "PageDown"),
+            KeyCode::Tab => write!(f, "Tab"),
+            KeyCode::Delete => write!(f, "Delete"),
+            KeyCode::Esc => write!(f, "Esc"),
+            other => write!(f, "{:?}", other),
+        }
+    }
+}
+
+/// The key bindings of every mode, in the order the help lists them.
+pub struct Keymap {
+    pub navigation: Vec<(KeyBinding, Action)>,
+    pub input: Vec<(KeyBinding, Action)>,
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        use KeyCode::*;
+        let navigation = vec![
+            (KeyBinding::char('?'), Action::Help),
+            (KeyBinding::char(':'), Action::Palette),
+            (KeyBinding::char('q'), Action::Quit),
+            (KeyBinding::char('h'), Action::Left),
+            (KeyBinding::key(Left), Action::Left),
+            (KeyBinding::char('l'), Action::Right),
+            (KeyBinding::key(Right), Action::Right),
+            (KeyBinding::char('k'), Action::Up),
+            (KeyBinding::key(Up), Action::Up),
+            (KeyBinding::char('j'), Action::Down),
+            (KeyBinding::key(Down), Action::Down),
+            (KeyBinding::key(PageUp), Action::PageUp),
+            (KeyBinding::ctrl(Char('u')), Action::PageUp),
+            (KeyBinding::key(PageDown), Action::PageDown),
+            (KeyBinding::ctrl(Char('d')), Action::PageDown),
+            (KeyBinding::ctrl(Up), Action::ScrollUp),
+            (KeyBinding::ctrl(Down), Action::ScrollDown),
+            (KeyBinding::char('f'), Action::ToggleFollow),
+            (KeyBinding::char('/'), Action::Search),
+            (KeyBinding::char('w'), Action::Watch),
+            (KeyBinding::key(Enter), Action::Watch),
+            (KeyBinding::char('t'), Action::Test),
+            (KeyBinding::char('r'), Action::Run),
+            (KeyBinding::char('B'), Action::Bench),
+            (KeyBinding::char('i'), Action::EditInput),
+            (KeyBinding::char('n'), Action::NextInput),
+            (KeyBinding::char('e'), Action::OpenEditor),
+            (KeyBinding::char('c'), Action::ToggleSolved),
+            (KeyBinding::char('S'), Action::Submit),
+            (KeyBinding::char('p'), Action::TogglePuzzle),
+            (KeyBinding::char('P'), Action::FetchPuzzle),
+            (KeyBinding::char('x'), Action::SaveExamples),
+            (KeyBinding::char('b'), Action::ToggleLeaderboard),
+            (KeyBinding::char('I'), Action::ToggleInspector),
+            (KeyBinding::char('H'), Action::ToggleHistory),
+            (KeyBinding::char('s'), Action::ToggleStats),
+            (KeyBinding::char('K'), Action::SideScrollUp),
+            (KeyBinding::char('J'), Action::SideScrollDown),
+        ];
+        let input = vec![
+            (KeyBinding::ctrl(Char('s')), Action::SaveInput),
+            (KeyBinding::ctrl(Enter), Action::SaveInput),
+            (KeyBinding::key(Esc), Action::CancelInput),
+            (KeyBinding::ctrl(Char('v')), Action::Paste),
+            (KeyBinding::key(Enter), Action::Newline),
+            (KeyBinding::key(Backspace), Action::DeleteBack),
+            (KeyBinding::key(Delete), Action::DeleteForward),
+            (KeyBinding::key(Left), Action::CursorLeft),
+            (KeyBinding::key(Right), Action::CursorRight),
+            (KeyBinding::key(Up), Action::CursorUp),
+            (KeyBinding::key(Down), Action::CursorDown),
+            (KeyBinding::ctrl(Left), Action::WordLeft),
+            (KeyBinding::ctrl(Right), Action::WordRight),
+            (KeyBinding::key(Home), Action::LineStart),
+            (KeyBinding::ctrl(Char('a')), Action::LineStart),
+            (KeyBinding::key(End), Action::LineEnd),
+            (KeyBinding::ctrl(Char('e')), Action::LineEnd),
+            (KeyBinding::ctrl(Home), Action::DocumentStart),
+            (KeyBinding::ctrl(End), Action::DocumentEnd),
+            (KeyBinding::shift(Left), Action::SelectLeft),
+            (KeyBinding::shift(Right), Action::SelectRight),
+            (KeyBinding::shift(Up), Action::SelectUp),
+            (KeyBinding::shift(Down), Action::SelectDown),
+            (KeyBinding::ctrl(Char('c')), Action::Copy),
+            (KeyBinding::ctrl(Char('x')), Action::Cut),
+            (KeyBinding::ctrl(Char('z')), Action::Undo),
+            (KeyBinding::ctrl(Char('y')), Action::Redo),
+            (KeyBinding::key(PageUp), Action::PageUp),
+            (KeyBinding::ctrl(Char('u')), Action::PageUp),
+            (KeyBinding::key(PageDown), Action::PageDown),
+            (KeyBinding::ctrl(Char('d')), Action::PageDown),
+            (KeyBinding::ctrl(Up), Action::ScrollUp),
+            (KeyBinding::ctrl(Down), Action::ScrollDown),
+        ];
+        Self { navigation, input }
+    }
+}
+
+impl Keymap {
+    /// The default keymap with the bindings of `[keys]` in `aocr.toml` applied.
+    pub fn from_config(config: &KeysConfig) -> Result<Self> {
+        let mut keymap = Self::default();
+        keymap
+            .apply(Mode::Navigation, &config.navigation)
+            .context("Invalid [keys.navigation] in aocr.toml")?;
+        keymap
+            .apply(Mode::Input, &config.input)
+            .context("Invalid [keys.input] in aocr.toml")?;
+        Ok(keymap)
+    }
+
+    /// Replace the keys of each named action. A key bound to another action moves over.
+    fn apply(&mut self, mode: Mode, overrides: &BTreeMap<String, Vec<String>>) -> Result<()> {
+        for (name, keys) in overrides {
+            let action =
+                Action::from_name(name).ok_or_else(|| anyhow!("Unknown action {:?}", name))?;
+            let keys = keys
+                .iter()
+                .map(|key| key.parse())
+                .collect::<Result<Vec<KeyBinding>>>()?;
+
+            let map = self.map_mut(mode);
+            map.retain(|(binding, bound)| *bound != action && !keys.contains(binding));
+            map.extend(keys.into_iter().map(|binding| (binding, action)));
+        }
+        Ok(())
+    }
+
+    fn map(&self, mode: Mode) -> &[(KeyBinding, Action)] {
+        match mode {
+            Mode::Navigation => &self.navigation,
+            Mode::Input => &self.input,
+        }
+    }
+
+    fn map_mut(&mut self, mode: Mode) -> &mut Vec<(KeyBinding, Action)> {
+        match mode {
+            Mode::Navigation => &mut self.navigation,
+            Mode::Input => &mut self.input,
+        }
+    }
+
+    /// The action bound to a key press in `mode`, if any.
+    pub fn lookup(&self, mode: Mode, event: &KeyEvent) -> Option<Action> {
+        let pressed = KeyBinding::from_event(event);
+        self.map(mode)
+            .iter()
+            .find(|(binding, _)| *binding == pressed)
+            .map(|(_, action)| *action)
+    }
+
+    /// Every action of `mode` with all of its keys, in the order they were bound.
+    pub fn bindings(&self, mode: Mode) -> Vec<(Action, Vec<KeyBinding>)> {
+        let mut grouped: Vec<(Action, Vec<KeyBinding>)> = Vec::new();
+        for (binding, action) in self.map(mode) {
+            match grouped.iter_mut().find(|(a, _)| a == action) {
+                Some((_, keys)) => keys.push(*binding),
+                None => grouped.push((*action, vec![*binding])),
+            }
+        }
+        grouped
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lookup() {
+        let keymap = Keymap::default();
+        let shifted = KeyEvent::new(KeyCode::Char('P'), KeyModifiers::SHIFT);
+        assert_eq!(
+            keymap.lookup(Mode::Navigation, &shifted),
+            Some(Action::FetchPuzzle)
+        );
+        let save = KeyEvent::new(KeyCode::Char('s'), KeyModifiers::CONTROL);
+        assert_eq!(keymap.lookup(Mode::Input, &save), Some(Action::SaveInput));
+        assert_eq!(keymap.lookup(Mode::Navigation, &save), None);
+
+        let bindings = keymap.bindings(Mode::Navigation);
+        let (_, keys) = bindings
+            .iter()
+            .find(|(action, _)| *action == Action::Left)
+            .unwrap();
+        let labels: Vec<String> = keys.iter().map(ToString::to_string).collect();
+        assert_eq!(labels, ["h", "Left"]);
+    }
+
+    #[test]
+    fn test_keymap_from_config() -> Result<()> {
+        assert_eq!(
+            "ctrl+s".parse::<KeyBinding>()?,
+            KeyBinding::ctrl(KeyCode::Char('s'))
+        );
+        assert_eq!("Shift+p".parse::<KeyBinding>()?, KeyBinding::char('P'));
+        assert_eq!(
+            "PageUp".parse::<KeyBinding>()?,
+            KeyBinding::key(KeyCode::PageUp)
+        );
+        assert!("hyper+x".parse::<KeyBinding>().is_err());
+
+        let config: KeysConfig = toml::from_str(
+            r#"
+            navigation = { left = ["a", "Left"], watch = [], run = ["w"] }
+            input = { save-input = ["ctrl+w"] }
+            "#,
+        )?;
+        let keymap = Keymap::from_config(&config)?;
+        let press = |c| KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE);
+        assert_eq!(
+            keymap.lookup(Mode::Navigation, &press('a')),
+            Some(Action::Left)
+        );
+        assert_eq!(keymap.lookup(Mode::Navigation, &press('h')), None);
+        // "w" moved from watch to run, and watch lost Enter too
+        assert_eq!(
+            keymap.lookup(Mode::Navigation, &press('w')),
+            Some(Action::Run)
+        );
+        assert_eq!(keymap.lookup(Mode::Navigation, &press('r')), None);
+        let enter = KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE);
+        assert_eq!(keymap.lookup(Mode::Navigation, &enter), None);
+        let save = KeyEvent::new(KeyCode::Char('w'), KeyModifiers::CONTROL);
+        assert_eq!(keymap.lookup(Mode::Input, &save), Some(Action::SaveInput));
+
+        let unknown: KeysConfig = toml::from_str(r#"navigation = { fly = ["f"] }"#)?;
+        assert!(Keymap::from_config(&unknown).is_err());
+        Ok(())
+    }
+}
@@ -1,2 +1,9 @@
 pub mod app;
+pub mod editor;
+pub mod external_editor;
+pub mod grid;
+pub mod keymap;
+pub mod palette;
+pub mod puzzle;
+pub mod search;
 pub mod ui;
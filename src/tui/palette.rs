@@ -0,0 +1,136 @@
+// src/tui/palette.rs
+use super::keymap::Action;
+
+/// Something the command palette can do.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Command {
+    Action(Action),
+    GoToDay(u8),
+}
+
+impl Command {
+    pub fn name(self) -> String {
+        match self {
+            Command::Action(action) => action.name().to_string(),
+            Command::GoToDay(day) => format!("day {}", day),
+        }
+    }
+
+    pub fn description(self) -> String {
+        match self {
+            Command::Action(action) => action.description().to_string(),
+            Command::GoToDay(day) => format!("Go to day {}", day),
+        }
+    }
+
+    /// How well the command matches `query`, preferring matches on its name.
+    fn score(self, query: &str) -> Option<i32> {
+        let name = fuzzy_score(query, &self.name()).map(|score| score * 2);
+        let description = fuzzy_score(query, &self.description());
+        name.max(description)
+    }
+}
+
+/// Score how well `query` matches `candidate`, or `None` if its characters do not all
+/// appear in order. Consecutive characters and matches at word starts score higher.
+pub fn fuzzy_score(query: &str, candidate: &str) -> Option<i32> {
+    let candidate: Vec<char> = candidate.to_lowercase().chars().collect();
+    let mut score = 0;
+    let mut position = 0;
+    let mut previous: Option<usize> = None;
+
+    for q in query.to_lowercase().chars().filter(|c| !c.is_whitespace()) {
+        let found = position + candidate[position..].iter().position(|&c| c == q)?;
+        score += 1;
+        if previous.is_some_and(|p| p + 1 == found) {
+            score += 5;
+        }
+        if found == 0 || !candidate[found - 1].is_alphanumeric() {
+            score += 8;
+        }
+        previous = Some(found);
+        position = found + 1;
+    }
+    Some(score)
+}
+
+/// State of the open command palette.
+#[derive(Debug, Default)]
+pub struct Palette {
+    pub query: String,
+    pub selected: usize,
+}
+
+impl Palette {
+    /// The commands matching the query, best first.
+    pub fn matches(&self) -> Vec<Command> {
+        let commands = Action::ALL
+            .into_iter()
+            .filter(|action| action.in_palette())
+            .map(Command::Action)
+            .chain((1..=25).map(Command::GoToDay));
+
+        let mut scored: Vec<(i32, usize, Command)> = commands
+            .enumerate()
+            .filter_map(|(i, command)| command.score(&self.query).map(|score| (score, i, command)))
+            .collect();
+        // Best score first, keeping the listed order for ties
+        scored.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(&b.1)));
+        scored.into_iter().map(|(_, _, command)| command).collect()
+    }
+
+    pub fn push(&mut self, c: char) {
+        self.query.push(c);
+        self.selected = 0;
+    }
+
+    pub fn pop(&mut self) {
+        self.query.pop();
+        self.selected = 0;
+    }
+
+    pub fn select_next(&mut self, count: usize) {
+        if self.selected + 1 < count {
+            self.selected += 1;
+        }
+    }
+
+    pub fn select_previous(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fuzzy_score() {
+        assert!(fuzzy_score("sub", "Submit the latest answer").is_some());
+        assert!(fuzzy_score("xyz", "Submit the latest answer").is_none());
+        // Matches at word starts win
+        assert!(fuzzy_score("ct", "cargo test") > fuzzy_score("ct", "select"));
+    }
+
+    #[test]
+    fn test_palette_matches() {
+        let mut palette = Palette::default();
+        for c in "day 12".chars() {
+            palette.push(c);
+        }
+        assert_eq!(palette.matches(), vec![Command::GoToDay(12)]);
+
+        palette.query = "sub".into();
+        assert_eq!(
+            palette.matches().first(),
+            Some(&Command::Action(Action::Submit))
+        );
+        palette.query = "bench".into();
+        assert_eq!(
+            palette.matches().first(),
+            Some(&Command::Action(Action::Bench))
+        );
+        palette.query.clear();
+        assert!(!palette.matches().contains(&Command::Action(Action::Up)));
+    }
+}
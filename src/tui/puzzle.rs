@@ -0,0 +1,80 @@
+// src/tui/puzzle.rs
+use crate::aoc::puzzle::{self, Block, Puzzle};
+use ratatui::{
+    style::{Color, Modifier, Style},
+    text::{Line, Span, Text},
+};
+
+fn span_style(style: puzzle::Style) -> Style {
+    let mut out = Style::default();
+    if style.code {
+        out = out.fg(Color::Yellow);
+    }
+    if style.em {
+        out = out.fg(Color::White).add_modifier(Modifier::BOLD);
+    }
+    if style.link {
+        out = out.add_modifier(Modifier::UNDERLINED);
+    }
+    out
+}
+
+fn spans(spans: &[puzzle::Span]) -> Vec<Span<'static>> {
+    spans
+        .iter()
+        .map(|s| Span::styled(s.text.clone(), span_style(s.style)))
+        .collect()
+}
+
+/// Convert a parsed puzzle into styled text for the puzzle pane.
+pub fn to_text(puzzle: &Puzzle) -> Text<'static> {
+    let mut lines = Vec::new();
+    for (i, blocks) in puzzle.parts.iter().enumerate() {
+        if i > 0 {
+            lines.push(Line::default());
+        }
+        for block in blocks {
+            match block {
+                Block::Heading(text) => {
+                    lines.push(Line::styled(
+                        text.clone(),
+                        Style::default()
+                            .fg(Color::Green)
+                            .add_modifier(Modifier::BOLD),
+                    ));
+                    lines.push(Line::default());
+                }
+                Block::Paragraph(content) => {
+                    lines.push(Line::from(spans(content)));
+                    lines.push(Line::default());
+                }
+                Block::ListItem(content) => {
+                    let mut line = vec![Span::raw("  - ")];
+                    line.extend(spans(content));
+                    lines.push(Line::from(line));
+                }
+                Block::Code(content) => {
+                    // Split the spans on newlines so each source line stays its own line
+                    let mut line = Vec::new();
+                    for span in content {
+                        let style = span_style(span.style).fg(Color::Yellow);
+                        let mut parts = span.text.split('\n').peekable();
+                        while let Some(part) = parts.next() {
+                            if !part.is_empty() {
+                                line.push(Span::styled(part.to_string(), style));
+                            }
+                            if parts.peek().is_some() {
+                                lines.push(Line::from(std::mem::take(&mut line)));
+                            }
+                        }
+                    }
+                    if !line.is_empty() {
+                        lines.push(Line::from(line));
+                    }
+                    lines.push(Line::default());
+                }
+            }
+        }
+    }
+    Text::from(lines)
+}
@@ -0,0 +1,180 @@
+// src/tui/search.rs
+use ansi_to_tui::IntoText;
+use ratatui::{
+    style::Style,
+    text::{Line, Span},
+};
+
+/// Incremental search through the output pane.
+#[derive(Debug, Default)]
+pub struct Search {
+    pub query: String,
+    /// Line of the match the output is scrolled to
+    pub current: Option<usize>,
+    /// Number of lines containing the query
+    pub count: usize,
+}
+
+impl Search {
+    /// Find the first line from `from` on, in `forward` direction and wrapping around, that
+    /// contains the query, ignoring case.
+    pub fn find(&mut self, output: &str, from: usize, forward: bool) -> Option<usize> {
+        let query = self.query.to_ascii_lowercase();
+        let matches: Vec<usize> = if query.is_empty() {
+            Vec::new()
+        } else {
+            strip_ansi(output)
+                .split('\n')
+                .enumerate()
+                .filter(|(_, line)| line.to_ascii_lowercase().contains(&query))
+                .map(|(i, _)| i)
+                .collect()
+        };
+        self.count = matches.len();
+        self.current = if forward {
+            matches
+                .iter()
+                .find(|&&line| line >= from)
+                .or(matches.first())
+        } else {
+            matches
+                .iter()
+                .rev()
+                .find(|&&line| line <= from)
+                .or(matches.last())
+        }
+        .copied();
+        self.current
+    }
+}
+
+/// Parse the ANSI colors of cargo output into styled lines, in one pass so colors that
+/// span several lines carry over.
+pub fn to_lines(text: &str) -> Vec<Line<'static>> {
+    let mut lines = text.into_text().map(|text| text.lines).unwrap_or_else(|_| {
+        text.split('\n')
+            .map(|line| Line::raw(line.to_string()))
+            .collect()
+    });
+    // Keep one line per `\n`-separated line of the text, trailing empty ones included
+    lines.resize(text.split('\n').count(), Line::default());
+    lines
+}
+
+/// The text without its ANSI escape sequences.
+pub fn strip_ansi(text: &str) -> String {
+    to_lines(text)
+        .iter()
+        .map(|line| {
+            line.spans
+                .iter()
+                .map(|span| span.content.as_ref())
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Restyle every occurrence of `query` in the line, ignoring case, keeping the colors
+/// around it.
+pub fn highlight(line: Line<'static>, query: &str, style: Style) -> Line<'static> {
+    if query.is_empty() {
+        return line;
+    }
+    let plain: String = line
+        .spans
+        .iter()
+        .map(|span| span.content.as_ref())
+        .collect::<String>()
+        .to_ascii_lowercase();
+    let query = query.to_ascii_lowercase();
+    let ranges: Vec<(usize, usize)> = plain
+        .match_indices(&query)
+        .map(|(start, matched)| (start, start + matched.len()))
+        .collect();
+    if ranges.is_empty() {
+        return line;
+    }
+
+    let mut spans = Vec::new();
+    let mut offset = 0;
+    for span in line.spans {
+        let content = span.content.as_ref();
+        let end = offset + content.len();
+        // Cut the span where matches start and end
+        let mut cuts = vec![offset, end];
+        for &(start, stop) in &ranges {
+            cuts.extend([start, stop].into_iter().filter(|&c| c > offset && c < end));
+        }
+        cuts.sort_unstable();
+        cuts.dedup();
+        for pair in cuts.windows(2) {
+            let (from, to) = (pair[0], pair[1]);
+            let matched = ranges
+                .iter()
+                .any(|&(start, stop)| start <= from && to <= stop);
+            let piece_style = if matched {
+                span.style.patch(style)
+            } else {
+                span.style
+            };
+            spans.push(Span::styled(
+                content[from - offset..to - offset].to_string(),
+                piece_style,
+            ));
+        }
+        offset = end;
+    }
+    Line::from(spans).style(line.style)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::style::{Color, Modifier};
+
+    #[test]
+    fn test_ansi_and_highlight() {
+        let output = "\x1b[1m\x1b[91merror\x1b[0m: mismatched types\n --> day01/src/lib.rs:3:5";
+        assert_eq!(
+            strip_ansi(output),
+            "error: mismatched types\n --> day01/src/lib.rs:3:5"
+        );
+
+        let line = to_lines(output).remove(0);
+        assert_eq!(line.spans[0].style.fg, Some(Color::LightRed));
+        let marked = Style::default().bg(Color::Yellow);
+        let line = highlight(line, "OR: MIS", marked);
+        let pieces: Vec<(&str, bool)> = line
+            .spans
+            .iter()
+            .map(|span| (span.content.as_ref(), span.style.bg == Some(Color::Yellow)))
+            .collect();
+        assert_eq!(
+            pieces,
+            [
+                ("err", false),
+                ("or", true),
+                (": mis", true),
+                ("matched types", false)
+            ]
+        );
+        // The match keeps the color of the text it covers
+        assert!(line.spans[1].style.add_modifier.contains(Modifier::BOLD));
+
+        let mut search = Search {
+            query: "LIB".into(),
+            ..Default::default()
+        };
+        assert_eq!(search.find(output, 0, true), Some(1));
+        assert_eq!(search.find(output, 0, false), Some(1));
+        assert_eq!(search.count, 1);
+
+        // A color set on one line still applies on the next
+        let lines = to_lines("\x1b[31mnote:\nstill red\x1b[0m\nplain\n");
+        assert_eq!(lines.len(), 4);
+        assert_eq!(lines[1].spans[0].content, "still red");
+        assert_eq!(lines[1].spans[0].style.fg, Some(Color::Red));
+        assert_ne!(lines[2].spans[0].style.fg, Some(Color::Red));
+    }
+}
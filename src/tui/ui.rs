@@ -1,107 +1,509 @@
-// stchrc/tui/ui.rs
+// src/tui/ui.rs
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
-    style::{Color, Style},
-    text::Span,
-    widgets::{Block, Borders, Paragraph, Row, Table},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Row, Table, Wrap},
     Frame,
 };
 
-use super::app::App;
+use super::app::{App, SidePane, Status};
+use super::editor::Position;
+use super::grid::{self, GridLayout};
+use super::keymap::Mode;
+use super::search;
+use crate::aoc::{self, leaderboard::Source};
+use crate::history;
+use std::collections::HashMap;
+use unicode_segmentation::UnicodeSegmentation;
 
 pub fn draw(f: &mut Frame, app: &mut App) {
+    let screen = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(0), Constraint::Length(1)])
+        .split(f.area());
+    draw_legend(f, screen[1]);
+
+    // The grid's rows sit inside its top and bottom border
+    let layout = GridLayout::fit(screen[0].height.saturating_sub(2));
+    let full_row = if app.show_stats { 55 } else { 27 };
     let chunks = Layout::default()
         .direction(Direction::Horizontal)
-        .constraints([Constraint::Length(9), Constraint::default()])
-        .split(f.area());
+        .constraints([
+            Constraint::Length(layout.width(full_row)),
+            Constraint::default(),
+        ])
+        .split(screen[0]);
+
+    draw_days_grid(f, app, chunks[0], layout);
 
-    draw_days_grid(f, app, chunks[0]);
+    let main = if let Some(pane) = app.side_pane {
+        let panes = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(60), Constraint::Percentage(40)])
+            .split(chunks[1]);
+        match pane {
+            SidePane::Puzzle => draw_puzzle_window(f, app, panes[0]),
+            SidePane::Leaderboard => draw_leaderboard_window(f, app, panes[0]),
+            SidePane::Inspector => draw_inspector_window(f, app, panes[0]),
+            SidePane::History => draw_history_window(f, app, panes[0]),
+        }
+        panes[1]
+    } else {
+        chunks[1]
+    };
 
     if app.input_mode {
-        draw_input_window(f, app, chunks[1]);
+        draw_input_window(f, app, main);
     } else {
-        draw_output_window(f, app, chunks[1]);
+        draw_output_window(f, app, main);
+    }
+
+    if app.show_help {
+        draw_help(f, app);
+    } else if app.palette.is_some() {
+        draw_palette(f, app);
     }
 }
 
-fn draw_days_grid(f: &mut Frame, app: &App, area: Rect) {
-    let rows: Vec<Row> = (1..=25)
-        .map(|day| {
-            let day_cell = Span::raw(format!("{:02}", day));
+/// A rectangle of at most `width` by `height` centered in `area`.
+fn centered(area: Rect, width: u16, height: u16) -> Rect {
+    let width = width.min(area.width);
+    let height = height.min(area.height);
+    Rect::new(
+        area.x + (area.width - width) / 2,
+        area.y + (area.height - height) / 2,
+        width,
+        height,
+    )
+}
 
-            // Part 1 cell
-            let part1_style = if app.days.get(&(day, 1)).map_or(false, |c| c.completed) {
-                Style::default().fg(Color::Green)
-            } else {
-                Style::default().fg(Color::White)
-            };
-            let part1_selected = day == app.selected_day && app.selected_part == 1;
-            let part1_cell = if part1_selected {
-                Span::styled("1", part1_style.bg(Color::DarkGray))
-            } else {
-                Span::styled("1", part1_style)
-            };
+fn draw_help(f: &mut Frame, app: &App) {
+    let mode = app.mode();
+    let bindings = app.keymap.bindings(mode);
+    let keys: Vec<String> = bindings
+        .iter()
+        .map(|(_, keys)| {
+            keys.iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(", ")
+        })
+        .collect();
+    let key_width = keys.iter().map(|k| k.len()).max().unwrap_or(0);
 
-            // Part 2 cell
-            let part2_style = if app.days.get(&(day, 2)).map_or(false, |c| c.completed) {
-                Style::default().fg(Color::Green)
+    let mut lines: Vec<Line> = bindings
+        .iter()
+        .zip(&keys)
+        .map(|((action, _), keys)| {
+            Line::from(vec![
+                Span::styled(
+                    format!("{:>width$}  ", keys, width = key_width),
+                    Style::default().fg(Color::Yellow),
+                ),
+                Span::raw(action.description()),
+                Span::styled(
+                    format!("  {}", action.name()),
+                    Style::default().fg(Color::DarkGray),
+                ),
+            ])
+        })
+        .collect();
+    if mode == Mode::Input {
+        lines.push(Line::raw(format!(
+            "{:>width$}  Type into the input",
+            "Other keys",
+            width = key_width
+        )));
+    }
+
+    let title = match mode {
+        Mode::Navigation => "Keys (press any key to close)",
+        Mode::Input => "Input keys (press any key to close)",
+    };
+    let area = centered(f.area(), 72, lines.len() as u16 + 2);
+    f.render_widget(Clear, area);
+    f.render_widget(
+        Paragraph::new(lines).block(Block::default().borders(Borders::ALL).title(title)),
+        area,
+    );
+}
+
+fn draw_palette(f: &mut Frame, app: &App) {
+    let Some(palette) = &app.palette else {
+        return;
+    };
+    let matches = palette.matches();
+
+    let area = centered(f.area(), 60, 14);
+    let visible = area.height.saturating_sub(3) as usize;
+    // Keep the selection in view
+    let first = palette.selected.saturating_sub(visible.saturating_sub(1));
+
+    let mut lines = vec![Line::from(vec![
+        Span::styled(": ", Style::default().fg(Color::Yellow)),
+        Span::raw(palette.query.as_str()),
+        Span::styled("_", Style::default().fg(Color::DarkGray)),
+    ])];
+    for (i, command) in matches.iter().enumerate().skip(first).take(visible) {
+        let style = if i == palette.selected {
+            Style::default().bg(Color::DarkGray)
+        } else {
+            Style::default()
+        };
+        lines.push(Line::from(vec![
+            Span::styled(format!("{:<16}", command.name()), style.fg(Color::Yellow)),
+            Span::styled(command.description(), style),
+        ]));
+    }
+    if matches.is_empty() {
+        lines.push(Line::styled(
+            "No matching command",
+            Style::default().fg(Color::DarkGray),
+        ));
+    }
+
+    f.render_widget(Clear, area);
+    f.render_widget(
+        Paragraph::new(lines).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("Command palette (Enter to run, Esc to close)"),
+        ),
+        area,
+    );
+}
+
+fn draw_puzzle_window(f: &mut Frame, app: &App, area: Rect) {
+    let block = Block::default().borders(Borders::ALL).title(format!(
+        "Puzzle day {} (P to refetch, J/K to scroll)",
+        app.selected_day
+    ));
+
+    let text = match &app.puzzle {
+        Some((day, puzzle)) if *day == app.selected_day => super::puzzle::to_text(puzzle),
+        _ => format!(
+            "Day {} has not been downloaded yet. Press 'P' to fetch it.",
+            app.selected_day
+        )
+        .into(),
+    };
+
+    let puzzle = Paragraph::new(text)
+        .block(block)
+        .wrap(Wrap { trim: false })
+        .scroll((app.side_scroll, 0));
+
+    f.render_widget(puzzle, area);
+}
+
+fn draw_leaderboard_window(f: &mut Frame, app: &App, area: Rect) {
+    let Some((leaderboard, source)) = &app.leaderboard else {
+        let block = Block::default().borders(Borders::ALL).title("Leaderboard");
+        f.render_widget(Paragraph::new("No leaderboard loaded").block(block), area);
+        return;
+    };
+
+    let age = match source {
+        Source::Downloaded => String::from("just downloaded"),
+        Source::Cached(age) => format!("cached {} ago", aoc::format_elapsed(age.as_secs())),
+    };
+    let block = Block::default().borders(Borders::ALL).title(format!(
+        "Leaderboard {} ({}, b to close, J/K to scroll)",
+        leaderboard.event, age
+    ));
+
+    let lines: Vec<Line> = leaderboard
+        .render(Some(app.selected_day))
+        .into_iter()
+        .enumerate()
+        .map(|(i, line)| {
+            if i == 0 {
+                Line::styled(line, Style::default().fg(Color::Green))
             } else {
-                Style::default().fg(Color::White)
-            };
-            let part2_selected = day == app.selected_day && app.selected_part == 2;
-            let part2_cell = if part2_selected {
-                Span::styled("2", part2_style.bg(Color::DarkGray))
+                Line::raw(line)
+            }
+        })
+        .collect();
+
+    let table = Paragraph::new(lines)
+        .block(block)
+        .scroll((app.side_scroll, 0));
+
+    f.render_widget(table, area);
+}
+
+fn draw_inspector_window(f: &mut Frame, app: &App, area: Rect) {
+    let block = Block::default().borders(Borders::ALL).title(format!(
+        "Input {} day {} part {} (I to close, J/K to scroll)",
+        app.input_name, app.selected_day, app.selected_part
+    ));
+    let Some(stats) = &app.inspection else {
+        f.render_widget(Paragraph::new("No input saved").block(block), area);
+        return;
+    };
+
+    let lines: Vec<Line> = stats
+        .warnings()
+        .into_iter()
+        .map(|warning| Line::styled(format!("! {}", warning), Style::default().fg(Color::Yellow)))
+        .chain(stats.render().into_iter().map(Line::raw))
+        .collect();
+
+    let paragraph = Paragraph::new(lines)
+        .block(block)
+        .scroll((app.side_scroll, 0));
+    f.render_widget(paragraph, area);
+}
+
+fn draw_history_window(f: &mut Frame, app: &App, area: Rect) {
+    let block = Block::default().borders(Borders::ALL).title(format!(
+        "History day {} (H to close, J/K to scroll)",
+        app.selected_day
+    ));
+    if app.history.is_empty() {
+        f.render_widget(Paragraph::new("No runs yet").block(block), area);
+        return;
+    }
+
+    // Answers that changed since the previous run of the same part and input stand out
+    let mut last_answers: HashMap<(u8, &str), &str> = HashMap::new();
+    let mut lines: Vec<Line> = Vec::new();
+    for entry in &app.history {
+        let changed = last_answers
+            .insert((entry.part, &entry.input), &entry.answer)
+            .is_some_and(|previous| previous != entry.answer);
+        let style = if changed {
+            Style::default().fg(Color::Yellow)
+        } else {
+            Style::default()
+        };
+        lines.push(Line::styled(entry.render(), style));
+    }
+    // Newest first
+    lines.reverse();
+    lines.insert(
+        0,
+        Line::styled(history::header(), Style::default().fg(Color::Green)),
+    );
+
+    let paragraph = Paragraph::new(lines)
+        .block(block)
+        .scroll((app.side_scroll, 0));
+    f.render_widget(paragraph, area);
+}
+
+fn status_color(status: Status) -> Color {
+    match status {
+        Status::NoInput => Color::DarkGray,
+        Status::Input => Color::White,
+        Status::TestsFailing => Color::Red,
+        Status::TestsPassing => Color::Cyan,
+        Status::Answered => Color::Yellow,
+        Status::WrongAnswer => Color::Magenta,
+        Status::Solved => Color::Green,
+    }
+}
+
+fn draw_legend(f: &mut Frame, area: Rect) {
+    let mut spans = vec![Span::raw(" ")];
+    for status in Status::ALL {
+        spans.push(Span::styled(
+            "■ ",
+            Style::default().fg(status_color(status)),
+        ));
+        spans.push(Span::raw(format!("{}  ", status.label())));
+    }
+    f.render_widget(Paragraph::new(Line::from(spans)), area);
+}
+
+/// The cells of one day: its number, both parts and, unless `compact`, the runtimes and stats.
+fn day_cells(app: &App, day: u8, compact: bool) -> Vec<Span<'static>> {
+    let mut cells = vec![Span::raw(format!("{:02}", day))];
+    let challenges = [1, 2].map(|part| app.days.get(&(day, part)));
+
+    for (part, challenge) in (1..=2).zip(challenges) {
+        let status = challenge.map_or(Status::NoInput, |c| c.status());
+        let mut style = Style::default().fg(status_color(status));
+        if day == app.selected_day && app.selected_part == part {
+            style = style.bg(Color::DarkGray);
+        }
+        cells.push(Span::styled(part.to_string(), style));
+    }
+    if compact {
+        return cells;
+    }
+
+    // Last runtime of each part
+    for challenge in challenges {
+        let runtime = challenge
+            .and_then(|c| c.state.last_runtime())
+            .map_or_else(String::new, |d| format!("{:.1?}", d));
+        cells.push(Span::styled(
+            format!("{:>7}", runtime),
+            Style::default().fg(Color::DarkGray),
+        ));
+    }
+
+    if app.show_stats {
+        for challenge in challenges {
+            let state = challenge.map(|c| &c.state);
+            let time = state
+                .and_then(|s| s.time_to_star(app.config.year, day))
+                .map_or_else(String::new, aoc::format_elapsed);
+            let wrong = state.map_or(0, |s| s.wrong_answers.len());
+            cells.push(Span::raw(format!("{:>10}", time)));
+            cells.push(if wrong > 0 {
+                Span::styled(format!("{:>2}", wrong), Style::default().fg(Color::Red))
             } else {
-                Span::styled("2", part2_style)
+                Span::raw("")
+            });
+        }
+    }
+    cells
+}
+
+fn draw_days_grid(f: &mut Frame, app: &mut App, area: Rect, layout: GridLayout) {
+    let day_widths = [3, 2, 2];
+    let (rows, widths, title): (Vec<Row>, Vec<u16>, String) = match layout {
+        GridLayout::Compact { columns, rows } => {
+            let table_rows = (0..rows)
+                .map(|row| {
+                    let mut cells = Vec::new();
+                    for column in 0..columns {
+                        let day = column * rows + row + 1;
+                        if column > 0 {
+                            // Empty spacer cell between day columns
+                            cells.push(Span::raw(""));
+                        }
+                        if day <= grid::DAYS {
+                            cells.extend(day_cells(app, day as u8, true));
+                        }
+                    }
+                    Row::new(cells)
+                })
+                .collect();
+            let mut widths = Vec::new();
+            for column in 0..columns {
+                if column > 0 {
+                    widths.push(0);
+                }
+                widths.extend([2, 1, 1]);
+            }
+            (table_rows, widths, grid_title(app, 1, grid::DAYS, false))
+        }
+        GridLayout::Full | GridLayout::Scroll { .. } => {
+            let visible = match layout {
+                GridLayout::Scroll { rows } => rows,
+                _ => grid::DAYS,
             };
+            app.grid_offset =
+                grid::scroll_offset(app.selected_day as u16 - 1, app.grid_offset, visible);
+            let first = app.grid_offset + 1;
+            let last = (app.grid_offset + visible).min(grid::DAYS);
+            let table_rows = (first..=last)
+                .map(|day| Row::new(day_cells(app, day as u8, false)))
+                .collect();
 
-            Row::new(vec![day_cell, part1_cell, part2_cell])
-        })
-        .collect();
+            let mut widths = day_widths.to_vec();
+            widths.extend([7, 7]); // Runtimes
+            if app.show_stats {
+                // Time to star and wrong attempts of each part
+                widths.extend([10, 2, 10, 2]);
+            }
+            (
+                table_rows,
+                widths,
+                grid_title(app, first, last, app.show_stats),
+            )
+        }
+    };
 
-    let widths = vec![
-        Constraint::Length(3), // Day number
-        Constraint::Length(2), // Part 1
-        Constraint::Length(2), // Part 2
-    ];
-    let table = Table::new(rows, widths)
-        .block(Block::default().borders(Borders::ALL).title("Days"))
+    let table = Table::new(rows, widths.into_iter().map(Constraint::Length))
+        .block(Block::default().borders(Borders::ALL).title(title))
         .column_spacing(1);
 
     f.render_widget(table, area);
 }
 
+/// Title of the days grid with the selected input, the stats legend when `stats` are
+/// shown, and which days are in view.
+fn grid_title(app: &App, first: u16, last: u16, stats: bool) -> String {
+    let mut title = String::from("Days");
+    if app.input_name != crate::input::DEFAULT_NAME {
+        title.push_str(&format!(" [{}]", app.input_name));
+    }
+    if stats {
+        title.push_str(" (runtime, time to star, wrong answers)");
+    }
+    if first > 1 || last < grid::DAYS {
+        let up = if first > 1 { "▲" } else { " " };
+        let down = if last < grid::DAYS { "▼" } else { " " };
+        title.push_str(&format!(" {}{}-{}/{}{}", up, first, last, grid::DAYS, down));
+    } else {
+        title.push_str(&format!(" {}/{}", app.selected_day, grid::DAYS));
+    }
+    title
+}
+
 fn draw_output_window(f: &mut Frame, app: &mut App, area: Rect) {
     let scroll_hint = if app.show_scroll_hint {
-        " (Use PageUp/PageDown or Ctrl+u/Ctrl+d to scroll)"
+        " (Use PageUp/PageDown or Ctrl+u/Ctrl+d to scroll, / to search)"
     } else {
         ""
     };
+    let follow = if app.follow {
+        "following"
+    } else {
+        "f to follow"
+    };
 
-    let block = Block::default().borders(Borders::ALL).title(format!(
-        "Cargo output day {} part {}{}",
-        app.watched_day, app.watched_part, scroll_hint
+    let mut block = Block::default().borders(Borders::ALL).title(format!(
+        "Cargo output day {} part {} ({}){}",
+        app.watched_day, app.watched_part, follow, scroll_hint
     ));
-
+    if let Some(search) = &app.search {
+        let found = match (search.current, search.count) {
+            (_, 0) if !search.query.is_empty() => String::from(" no matches"),
+            (Some(line), count) => format!(" line {} of {} matching", line + 1, count),
+            _ => String::new(),
+        };
+        block = block.title_bottom(format!(
+            " /{}_{} (Enter/Up to step, Esc to close) ",
+            search.query, found
+        ));
+    }
     let inner_area = block.inner(area);
 
-    let check_output = Paragraph::new(app.cargo_output.as_str())
-        .block(block)
-        .scroll((app.scroll, 0));
-
-    f.render_widget(check_output, area);
+    // Stick to the newest line when following, otherwise stay where the user scrolled
+    let lines = search::to_lines(&app.cargo_output);
+    let last_scroll = lines.len().saturating_sub(inner_area.height as usize) as u16;
+    if app.follow || app.scroll > last_scroll {
+        app.scroll = last_scroll;
+    }
 
-    // Adjust scroll if it would put the cursor out of view
-    let lines: Vec<&str> = app.cargo_output.split('\n').collect();
-    let newest_line = lines.len().saturating_sub(1) as u16;
+    // Only the visible lines are highlighted
+    let marked = Style::default().bg(Color::Yellow).fg(Color::Black);
+    let current = marked.bg(Color::LightYellow).add_modifier(Modifier::BOLD);
+    let text: Vec<Line> = lines
+        .into_iter()
+        .enumerate()
+        .skip(app.scroll as usize)
+        .take(inner_area.height as usize)
+        .map(|(i, line)| match &app.search {
+            Some(search) => {
+                let style = if search.current == Some(i) {
+                    current
+                } else {
+                    marked
+                };
+                search::highlight(line, &search.query, style)
+            }
+            None => line,
+        })
+        .collect();
 
-    if newest_line < app.scroll {
-        // Cursor is above visible area
-        app.scroll = newest_line;
-    } else if newest_line >= app.scroll + inner_area.height {
-        // Cursor is below visible area
-        app.scroll = newest_line.saturating_sub(inner_area.height) + 1;
-    }
+    f.render_widget(Paragraph::new(text).block(block), area);
 }
 
 fn draw_input_window(f: &mut Frame, app: &mut App, area: Rect) {
@@ -110,34 +512,61 @@ fn draw_input_window(f: &mut Frame, app: &mut App, area: Rect) {
     } else {
         ""
     };
-    // Create the text with cursor
-    let mut text = app.current_input.clone();
-    if app.cursor_blink_state && app.input_mode {
-        // Insert the cursor at the current position
-        text.insert(app.cursor_position, '_');
-    }
-
     let block = Block::default().borders(Borders::ALL).title(format!(
-        "Input (Ctrl+S or Ctrl+Enter to save, Ctrl+V to paste){}",
-        scroll_hint
+        "Input {} (Ctrl+S or Ctrl+Enter to save, Ctrl+V to paste, Ctrl+Z to undo){}",
+        app.input_name, scroll_hint
     ));
     let inner_area = block.inner(area);
 
-    let input = Paragraph::new(text).block(block).scroll((app.scroll, 0));
-
-    f.render_widget(input, area);
-
     // Adjust scroll if it would put the cursor out of view
-    let lines: Vec<&str> = app.current_input[..app.cursor_position]
-        .split('\n')
-        .collect();
-    let cursor_line = lines.len().saturating_sub(1) as u16;
-
-    if cursor_line < app.scroll {
+    let cursor = app.editor.cursor();
+    let cursor_line = cursor.line as u16;
+    if cursor_line < app.input_scroll {
         // Cursor is above visible area
-        app.scroll = cursor_line;
-    } else if cursor_line >= app.scroll + inner_area.height {
+        app.input_scroll = cursor_line;
+    } else if cursor_line >= app.input_scroll + inner_area.height {
         // Cursor is below visible area
-        app.scroll = cursor_line.saturating_sub(inner_area.height) + 1;
+        app.input_scroll = cursor_line.saturating_sub(inner_area.height) + 1;
     }
+    // Scroll sideways when the cursor is past the right edge
+    let width = inner_area.width.max(1) as usize;
+    let first_column = (app.editor.cursor_column() + 1).saturating_sub(width);
+
+    // Only the visible lines are styled, so long inputs stay fast
+    let selection = app.editor.selection();
+    let show_cursor = app.cursor_blink_state && app.input_mode;
+    let lines: Vec<Line> = app
+        .editor
+        .lines()
+        .iter()
+        .enumerate()
+        .skip(app.input_scroll as usize)
+        .take(inner_area.height as usize)
+        .map(|(number, line)| {
+            let mut spans: Vec<Span> = Vec::new();
+            let graphemes = line
+                .grapheme_indices(true)
+                .chain(std::iter::once((line.len(), " ")))
+                .skip(first_column)
+                .take(width);
+            for (col, grapheme) in graphemes {
+                let position = Position { line: number, col };
+                let style = if show_cursor && position == cursor {
+                    Style::default().add_modifier(Modifier::REVERSED)
+                } else if selection.is_some_and(|(start, end)| start <= position && position < end)
+                {
+                    Style::default().bg(Color::DarkGray)
+                } else {
+                    Style::default()
+                };
+                match spans.last_mut() {
+                    Some(span) if span.style == style => span.content.to_mut().push_str(grapheme),
+                    _ => spans.push(Span::styled(grapheme.to_string(), style)),
+                }
+            }
+            Line::from(spans)
+        })
+        .collect();
+
+    f.render_widget(Paragraph::new(lines).block(block), area);
 }
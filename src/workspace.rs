@@ -0,0 +1,229 @@
+// src/workspace.rs
+use anyhow::{Context, Result};
+use std::fs;
+use std::path::Path;
+use toml_edit::{value, Array, DocumentMut, InlineTable, Item, Table, Value};
+
+/// Read the `Cargo.toml` of the workspace or crate at `dir`.
+fn read_manifest(dir: &Path) -> Result<DocumentMut> {
+    let path = dir.join("Cargo.toml");
+    let contents =
+        fs::read_to_string(&path).with_context(|| format!("Failed to read {:?}", path))?;
+    contents
+        .parse()
+        .with_context(|| format!("Failed to parse {:?}", path))
+}
+
+fn write_manifest(dir: &Path, manifest: &DocumentMut) -> Result<()> {
+    fs::write(dir.join("Cargo.toml"), manifest.to_string())?;
+    Ok(())
+}
+
+/// Get the table at `key`, creating it if it is missing.
+fn table_mut<'a>(parent: &'a mut Table, key: &str) -> Result<&'a mut Table> {
+    parent
+        .entry(key)
+        .or_insert_with(|| Item::Table(Table::new()))
+        .as_table_mut()
+        .with_context(|| format!("`{}` in Cargo.toml is not a table", key))
+}
+
+/// The entries of the workspace `members` list.
+pub fn members(manifest: &DocumentMut) -> Vec<String> {
+    manifest
+        .get("workspace")
+        .and_then(|w| w.get("members"))
+        .and_then(Item::as_array)
+        .map(|members| {
+            members
+                .iter()
+                .filter_map(|m| m.as_str().map(str::to_string))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Add `member` to the workspace `members` list, keeping the existing formatting.
+///
+/// Day crates are inserted in day order. Returns `false` if it was already a member.
+pub fn insert_member(manifest: &mut DocumentMut, member: &str) -> Result<bool> {
+    let workspace = table_mut(manifest.as_table_mut(), "workspace")?;
+    let members = workspace
+        .entry("members")
+        .or_insert_with(|| value(Array::new()))
+        .as_array_mut()
+        .context("`workspace.members` in Cargo.toml is not a list")?;
+
+    if members.iter().any(|m| m.as_str() == Some(member)) {
+        return Ok(false);
+    }
+
+    // Insert before the first member that sorts after this one, ignoring non-day crates
+    let position = members
+        .iter()
+        .position(|m| {
+            m.as_str()
+                .is_some_and(|m| m.starts_with("day") && m > member)
+        })
+        .unwrap_or(members.len());
+    members.insert(position, member);
+
+    // Give the new entry the same leading whitespace as its neighbor
+    let neighbor = if position + 1 < members.len() {
+        position + 1
+    } else {
+        position.saturating_sub(1)
+    };
+    if neighbor != position {
+        let prefix = members
+            .get(neighbor)
+            .and_then(|m| m.decor().prefix())
+            .and_then(|p| p.as_str())
+            .unwrap_or(" ")
+            .to_string();
+        if let Some(Value::String(s)) = members.get_mut(position) {
+            s.decor_mut().set_prefix(prefix);
+        }
+    }
+
+    Ok(true)
+}
+
+/// Declare a dependency in `[workspace.dependencies]` so members can inherit it.
+///
+/// Returns `false` if it was already declared.
+pub fn insert_workspace_dependency(
+    manifest: &mut DocumentMut,
+    name: &str,
+    dependency: Item,
+) -> Result<bool> {
+    let workspace = table_mut(manifest.as_table_mut(), "workspace")?;
+    let dependencies = table_mut(workspace, "dependencies")?;
+    if dependencies.contains_key(name) {
+        return Ok(false);
+    }
+    dependencies.insert(name, dependency);
+    Ok(true)
+}
+
+/// Add `name.workspace = true` to the `[dependencies]` of a crate manifest.
+///
+/// Returns `false` if the crate already depends on `name`.
+pub fn insert_inherited_dependency(manifest: &mut DocumentMut, name: &str) -> Result<bool> {
+    let dependencies = table_mut(manifest.as_table_mut(), "dependencies")?;
+    if dependencies.contains_key(name) {
+        return Ok(false);
+    }
+    let mut dependency = InlineTable::new();
+    dependency.insert("workspace", true.into());
+    dependency.set_dotted(true);
+    dependencies.insert(name, value(dependency));
+    Ok(true)
+}
+
+/// A `{ path = "..." }` dependency.
+pub fn path_dependency(path: &str) -> Item {
+    let mut dependency = InlineTable::new();
+    dependency.insert("path", path.into());
+    value(dependency)
+}
+
+/// Add `member` to the workspace at `root`. See [`insert_member`].
+pub fn add_member(root: &Path, member: &str) -> Result<bool> {
+    let mut manifest = read_manifest(root)?;
+    let added = insert_member(&mut manifest, member)?;
+    if added {
+        write_manifest(root, &manifest)?;
+    }
+    Ok(added)
+}
+
+/// Declare a path crate in the `[workspace.dependencies]` of the workspace at `root`.
+pub fn add_workspace_dependency(root: &Path, name: &str, path: &str) -> Result<()> {
+    let mut manifest = read_manifest(root)?;
+    if insert_workspace_dependency(&mut manifest, name, path_dependency(path))? {
+        write_manifest(root, &manifest)?;
+    }
+    Ok(())
+}
+
+/// Make the crate at `crate_dir` inherit the workspace dependency `name`.
+pub fn inherit_dependency(crate_dir: &Path, name: &str) -> Result<()> {
+    let mut manifest = read_manifest(crate_dir)?;
+    if insert_inherited_dependency(&mut manifest, name)? {
+        write_manifest(crate_dir, &manifest)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_add_member_preserves_formatting() -> Result<()> {
+        let dir = TempDir::new()?;
+        let manifest = r#"[workspace]
+members = [
+    "runner",
+    "day01",
+    "day05",
+]
+resolver = "2"
+
+# Shared dependencies
+[workspace.dependencies]
+anyhow = "1.0.75"
+"#;
+        fs::write(dir.path().join("Cargo.toml"), manifest)?;
+
+        assert!(add_member(dir.path(), "day03")?);
+        assert!(add_member(dir.path(), "day07")?);
+        assert!(!add_member(dir.path(), "day03")?);
+
+        let updated = fs::read_to_string(dir.path().join("Cargo.toml"))?;
+        assert_eq!(
+            updated,
+            r#"[workspace]
+members = [
+    "runner",
+    "day01",
+    "day03",
+    "day05",
+    "day07",
+]
+resolver = "2"
+
+# Shared dependencies
+[workspace.dependencies]
+anyhow = "1.0.75"
+"#
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_add_dependencies() -> Result<()> {
+        let dir = TempDir::new()?;
+        fs::write(
+            dir.path().join("Cargo.toml"),
+            "[workspace]\nmembers = []\n\n[workspace.dependencies]\nanyhow = \"1.0.75\"\n",
+        )?;
+        add_workspace_dependency(dir.path(), "common", "common")?;
+        let updated = fs::read_to_string(dir.path().join("Cargo.toml"))?;
+        assert!(updated.ends_with("anyhow = \"1.0.75\"\ncommon = { path = \"common\" }\n"));
+
+        let day = dir.path().join("day01");
+        fs::create_dir_all(&day)?;
+        fs::write(
+            day.join("Cargo.toml"),
+            "[package]\nname = \"day01\"\n\n[dependencies]\nanyhow.workspace = true\n\n[lib]\npath = \"src/lib.rs\"\n",
+        )?;
+        inherit_dependency(&day, "common")?;
+        inherit_dependency(&day, "common")?;
+        let updated = fs::read_to_string(day.join("Cargo.toml"))?;
+        assert!(updated.contains("anyhow.workspace = true\ncommon.workspace = true\n\n[lib]"));
+        Ok(())
+    }
+}
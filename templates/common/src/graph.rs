@@ -0,0 +1,74 @@
+//! Breadth-first search and Dijkstra's shortest paths over implicit graphs.
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+
+/// Number of steps from `start` to every reachable node.
+pub fn bfs<N, I>(start: N, mut neighbors: impl FnMut(&N) -> I) -> HashMap<N, usize>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = N>,
+{
+    let mut distances = HashMap::from([(start.clone(), 0)]);
+    let mut queue = VecDeque::from([start]);
+    while let Some(node) = queue.pop_front() {
+        let distance = distances[&node];
+        for next in neighbors(&node) {
+            if !distances.contains_key(&next) {
+                distances.insert(next.clone(), distance + 1);
+                queue.push_back(next);
+            }
+        }
+    }
+    distances
+}
+
+/// Cost of the cheapest path from `start` to every reachable node.
+///
+/// `neighbors` yields `(node, edge cost)` pairs.
+pub fn dijkstra<N, I>(start: N, mut neighbors: impl FnMut(&N) -> I) -> HashMap<N, usize>
+where
+    N: Eq + Hash + Clone + Ord,
+    I: IntoIterator<Item = (N, usize)>,
+{
+    let mut costs = HashMap::from([(start.clone(), 0)]);
+    let mut heap = BinaryHeap::from([Reverse((0, start))]);
+    while let Some(Reverse((cost, node))) = heap.pop() {
+        if costs.get(&node).is_some_and(|&best| cost > best) {
+            continue;
+        }
+        for (next, edge) in neighbors(&node) {
+            let next_cost = cost + edge;
+            if costs.get(&next).is_none_or(|&best| next_cost < best) {
+                costs.insert(next.clone(), next_cost);
+                heap.push(Reverse((next_cost, next)));
+            }
+        }
+    }
+    costs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bfs() {
+        let distances = bfs(0u32, |&n| if n < 5 { vec![n + 1, n * 2] } else { vec![] });
+        assert_eq!(distances[&0], 0);
+        assert_eq!(distances[&4], 3);
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let edges = HashMap::from([
+            ('a', vec![('b', 7), ('c', 2)]),
+            ('c', vec![('b', 3)]),
+            ('b', vec![('d', 1)]),
+        ]);
+        let costs = dijkstra('a', |n| edges.get(n).cloned().unwrap_or_default());
+        assert_eq!(costs[&'b'], 5);
+        assert_eq!(costs[&'d'], 6);
+    }
+}
@@ -0,0 +1,131 @@
+//! A 2D grid with bounds-checked neighbor lookups.
+
+use std::ops::{Index, IndexMut};
+
+pub type Point = (usize, usize);
+
+pub const ORTHOGONAL: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+pub const DIAGONAL: [(isize, isize); 4] = [(1, -1), (1, 1), (-1, 1), (-1, -1)];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    pub width: usize,
+    pub height: usize,
+    pub cells: Vec<T>,
+}
+
+impl Grid<u8> {
+    /// Parse a block of equally wide lines into a grid of bytes.
+    pub fn parse(input: &str) -> Self {
+        Self::parse_with(input, |b| b)
+    }
+}
+
+impl<T> Grid<T> {
+    /// Parse a block of equally wide lines, mapping every byte to a cell.
+    pub fn parse_with(input: &str, f: impl Fn(u8) -> T) -> Self {
+        let lines: Vec<&str> = input.lines().filter(|l| !l.is_empty()).collect();
+        let width = lines.first().map_or(0, |l| l.len());
+        let height = lines.len();
+        let cells = lines.iter().flat_map(|l| l.bytes()).map(f).collect();
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    pub fn contains(&self, (x, y): Point) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.contains(point)
+            .then(|| &self.cells[point.1 * self.width + point.0])
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        if self.contains(point) {
+            Some(&mut self.cells[point.1 * self.width + point.0])
+        } else {
+            None
+        }
+    }
+
+    /// Move `point` by `(dx, dy)`, returning `None` when leaving the grid.
+    pub fn step(&self, (x, y): Point, (dx, dy): (isize, isize)) -> Option<Point> {
+        let next = (x.checked_add_signed(dx)?, y.checked_add_signed(dy)?);
+        self.contains(next).then_some(next)
+    }
+
+    /// The up to four orthogonal neighbors of `point`.
+    pub fn neighbors(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        ORTHOGONAL
+            .into_iter()
+            .filter_map(move |delta| self.step(point, delta))
+    }
+
+    /// The up to eight orthogonal and diagonal neighbors of `point`.
+    pub fn neighbors8(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        ORTHOGONAL
+            .into_iter()
+            .chain(DIAGONAL)
+            .filter_map(move |delta| self.step(point, delta))
+    }
+
+    /// Iterate over every cell as `(point, value)`.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> + '_ {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(i, cell)| ((i % self.width, i / self.width), cell))
+    }
+
+    /// The first point whose cell satisfies `predicate`.
+    pub fn find(&self, predicate: impl Fn(&T) -> bool) -> Option<Point> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(point, _)| point)
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        self.get(point).expect("point outside of grid")
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        self.get_mut(point).expect("point outside of grid")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_grid() {
+        let grid = Grid::parse("abc\ndef\n");
+        assert_eq!((grid.width, grid.height), (3, 2));
+        assert_eq!(grid[(1, 1)], b'e');
+        assert_eq!(grid.neighbors((0, 0)).count(), 2);
+        assert_eq!(grid.neighbors8((1, 0)).count(), 5);
+        assert_eq!(grid.find(|&c| c == b'f'), Some((2, 1)));
+        assert_eq!(grid.step((2, 1), (1, 0)), None);
+    }
+}
@@ -0,0 +1,3 @@
+//! Helpers shared by every day of Advent of Code {{year}}.
+//!
+//! Install curated modules with `aocr common add <module>` or add your own.
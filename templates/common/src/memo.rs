@@ -0,0 +1,54 @@
+//! Memoization for recursive functions.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// A cache for a recursive function of `K`.
+///
+/// ```
+/// use common::memo::Memo;
+///
+/// fn fib(memo: &mut Memo<u64, u64>, n: u64) -> u64 {
+///     memo.get_or_compute(n, |memo| if n < 2 { n } else { fib(memo, n - 1) + fib(memo, n - 2) })
+/// }
+///
+/// assert_eq!(fib(&mut Memo::new(), 80), 23416728348467685);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Memo<K, V> {
+    cache: HashMap<K, V>,
+}
+
+impl<K: Eq + Hash, V: Clone> Memo<K, V> {
+    pub fn new() -> Self {
+        Self {
+            cache: HashMap::new(),
+        }
+    }
+
+    /// Return the cached value for `key`, computing it with `f` on a miss.
+    ///
+    /// `f` receives the memo so it can recurse into other keys.
+    pub fn get_or_compute(&mut self, key: K, f: impl FnOnce(&mut Self) -> V) -> V {
+        if let Some(value) = self.cache.get(&key) {
+            return value.clone();
+        }
+        let value = f(self);
+        self.cache.insert(key, value.clone());
+        value
+    }
+
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+}
+
+impl<K: Eq + Hash, V: Clone> Default for Memo<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
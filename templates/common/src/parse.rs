@@ -0,0 +1,56 @@
+//! Pull integers out of free-form puzzle text.
+
+/// Every signed integer in `text`, in order of appearance.
+///
+/// A `-` only counts as a sign when it does not follow a letter or digit, so
+/// ranges such as `3-5` yield `3, 5`.
+pub fn ints(text: &str) -> Vec<i64> {
+    let bytes = text.as_bytes();
+    let mut numbers = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        let negative = bytes[i] == b'-'
+            && bytes.get(i + 1).is_some_and(u8::is_ascii_digit)
+            && (i == 0 || !bytes[i - 1].is_ascii_alphanumeric());
+        if bytes[i].is_ascii_digit() || negative {
+            let start = i;
+            i += 1;
+            while i < bytes.len() && bytes[i].is_ascii_digit() {
+                i += 1;
+            }
+            if let Ok(n) = text[start..i].parse() {
+                numbers.push(n);
+            }
+        } else {
+            i += 1;
+        }
+    }
+    numbers
+}
+
+/// Every unsigned integer in `text`, ignoring minus signs.
+pub fn uints(text: &str) -> Vec<u64> {
+    text.split(|c: char| !c.is_ascii_digit())
+        .filter_map(|s| s.parse().ok())
+        .collect()
+}
+
+/// The integers of every non-empty line.
+pub fn lines_of_ints(text: &str) -> Vec<Vec<i64>> {
+    text.lines()
+        .filter(|l| !l.trim().is_empty())
+        .map(ints)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ints() {
+        assert_eq!(ints("p=0,4 v=-3,-12 3-5"), vec![0, 4, -3, -12, 3, 5]);
+        assert_eq!(uints("p=0,4 v=-3"), vec![0, 4, 3]);
+        assert_eq!(lines_of_ints("1 2\n\n3 -4\n"), vec![vec![1, 2], vec![3, -4]]);
+    }
+}
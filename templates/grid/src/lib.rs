@@ -0,0 +1,83 @@
+//! Solution for Advent of Code {{year}}, Day {{day}}
+
+/// A rectangular grid of bytes parsed from the puzzle input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid {
+    pub width: usize,
+    pub height: usize,
+    pub cells: Vec<u8>,
+}
+
+impl Grid {
+    pub fn parse(input: &str) -> Self {
+        let lines: Vec<&[u8]> = input.lines().map(str::as_bytes).collect();
+        let width = lines.first().map_or(0, |line| line.len());
+        let height = lines.len();
+        let cells = lines.concat();
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<u8> {
+        (x < self.width && y < self.height).then(|| self.cells[y * self.width + x])
+    }
+
+    /// Orthogonal neighbors of `(x, y)` that lie inside the grid.
+    pub fn neighbors(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        [(0, -1), (1, 0), (0, 1), (-1, 0)]
+            .into_iter()
+            .filter_map(move |(dx, dy)| {
+                let nx = x.checked_add_signed(dx)?;
+                let ny = y.checked_add_signed(dy)?;
+                (nx < self.width && ny < self.height).then_some((nx, ny))
+            })
+    }
+
+    /// Iterate over every cell as `((x, y), value)`.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), u8)> + '_ {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(i, &c)| ((i % self.width, i / self.width), c))
+    }
+}
+
+pub fn part1(input: &str) -> usize {
+    let _grid = Grid::parse(input);
+    // TODO: Implement part 1 solution
+    0
+}
+
+pub fn part2(input: &str) -> usize {
+    let _grid = Grid::parse(input);
+    // TODO: Implement part 2 solution
+    0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "ab\ncd\n";
+
+    #[test]
+    fn test_grid() {
+        let grid = Grid::parse(EXAMPLE);
+        assert_eq!((grid.width, grid.height), (2, 2));
+        assert_eq!(grid.get(1, 1), Some(b'd'));
+        assert_eq!(grid.neighbors(0, 0).count(), 2);
+    }
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(EXAMPLE), 0);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(EXAMPLE), 0);
+    }
+}
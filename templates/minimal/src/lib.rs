@@ -0,0 +1,28 @@
+//! Solution for Advent of Code {{year}}, Day {{day}}
+
+pub fn part1(input: &str) -> usize {
+    // TODO: Implement part 1 solution
+    0
+}
+
+pub fn part2(input: &str) -> usize {
+    // TODO: Implement part 2 solution
+    0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let input = "";
+        assert_eq!(part1(input), 0);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = "";
+        assert_eq!(part2(input), 0);
+    }
+}
@@ -0,0 +1,55 @@
+//! Solution for Advent of Code {{year}}, Day {{day}}
+
+use nom::{
+    character::complete::{i64 as number, line_ending, space1},
+    multi::separated_list0,
+    IResult,
+};
+
+/// Parsed puzzle input shared by both parts.
+pub type Input = Vec<Vec<i64>>;
+
+fn line(input: &str) -> IResult<&str, Vec<i64>> {
+    separated_list0(space1, number)(input)
+}
+
+pub fn parse(input: &str) -> Input {
+    // TODO: Adjust the parser to the puzzle input
+    let (_, parsed) = separated_list0(line_ending, line)(input.trim_end())
+        .expect("Failed to parse input");
+    parsed
+}
+
+pub fn part1(input: &str) -> usize {
+    let _input = parse(input);
+    // TODO: Implement part 1 solution
+    0
+}
+
+pub fn part2(input: &str) -> usize {
+    let _input = parse(input);
+    // TODO: Implement part 2 solution
+    0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "1 2 3\n4 5 6\n";
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(parse(EXAMPLE), vec![vec![1, 2, 3], vec![4, 5, 6]]);
+    }
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(EXAMPLE), 0);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(EXAMPLE), 0);
+    }
+}
@@ -0,0 +1,43 @@
+//! Solution for Advent of Code {{year}}, Day {{day}}
+
+/// Parsed puzzle input shared by both parts.
+pub type Input<'a> = Vec<&'a str>;
+
+pub fn parse(input: &str) -> Input<'_> {
+    // TODO: Parse the puzzle input
+    input.lines().collect()
+}
+
+pub fn part1(input: &str) -> usize {
+    let _input = parse(input);
+    // TODO: Implement part 1 solution
+    0
+}
+
+pub fn part2(input: &str) -> usize {
+    let _input = parse(input);
+    // TODO: Implement part 2 solution
+    0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "";
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(parse(EXAMPLE).len(), 0);
+    }
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(EXAMPLE), 0);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(EXAMPLE), 0);
+    }
+}